@@ -0,0 +1,256 @@
+//! Derive macros for newtypes over [`numwit`](https://docs.rs/numwit) witnesses.
+//!
+//! Use these through `numwit`'s `derive` feature, rather than depending on
+//! this crate directly.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Ident, Member, Type};
+
+/// Forward operators, [`Deref`](std::ops::Deref), [`Display`](std::fmt::Display)
+/// and serde from a `Positive` or `Negative` field to a newtype.
+///
+/// The rows of the operation table whose output has the same sign as the
+/// field are forwarded, with the newtype as output:
+///
+/// | Newtype over | Operation | RHS                         | Assignable? |
+/// | ------------ | --------- | --------------------------- | ----------- |
+/// | `Positive`   | Add       | `Self`, `impl Unsigned`     | Yes         |
+/// |              | Sub       | `Negative`                  | Yes         |
+/// |              | Mul       | `Self`                      | Yes         |
+/// |              | Div       | `Self`, `impl Unsigned`     | Yes         |
+/// | `Negative`   | Add       | `Self`                      | Yes         |
+/// |              | Sub       | `Positive`                  | Yes         |
+/// |              | Mul       | `Positive`                  | Yes         |
+/// |              | Div       | `Positive`                  | Yes         |
+///
+/// The newtype must have exactly one field, whose type is named `Positive<..>`
+/// or `Negative<..>`.
+#[proc_macro_derive(WitnessOps)]
+pub fn witness_ops(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Sign {
+    Positive,
+    Negative,
+}
+
+impl Sign {
+    fn opposite(self) -> Self {
+        match self {
+            Sign::Positive => Sign::Negative,
+            Sign::Negative => Sign::Positive,
+        }
+    }
+    fn ident(self) -> Ident {
+        match self {
+            Sign::Positive => Ident::new("Positive", Span::call_site()),
+            Sign::Negative => Ident::new("Negative", Span::call_site()),
+        }
+    }
+}
+
+/// Find the sign of `ty`, and the type with the opposite sign.
+fn witness(ty: &Type) -> syn::Result<(Sign, Type)> {
+    let err = || {
+        syn::Error::new_spanned(
+            ty,
+            "`WitnessOps` requires a field of type `Positive<..>` or `Negative<..>`",
+        )
+    };
+    let Type::Path(path) = ty else {
+        return Err(err());
+    };
+    let last = path.path.segments.last().ok_or_else(err)?;
+    let sign = match last.ident.to_string().as_str() {
+        "Positive" => Sign::Positive,
+        "Negative" => Sign::Negative,
+        _ => return Err(err()),
+    };
+    // Name the opposite alias through `::numwit`, because the caller may not
+    // have imported it.
+    let opposite = sign.opposite().ident();
+    let args = &last.arguments;
+    Ok((sign, parse_quote!(::numwit::#opposite #args)))
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input,
+            "`WitnessOps` can only be derived for structs",
+        ));
+    };
+    let field = match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "`WitnessOps` requires exactly one field",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    let inner = &field.ty;
+    let (sign, opposite) = witness(inner)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let where_preds = where_clause.map(|it| &it.predicates);
+    let wrap = |expr: TokenStream| quote!(#name { #member: #expr });
+
+    let mut rows = vec![];
+    let mut row = |op: &str, rhs: Rhs| {
+        let op_trait = Ident::new(op, Span::call_site());
+        let op_fn = Ident::new(&op.to_lowercase(), Span::call_site());
+        let assign_trait = Ident::new(&format!("{op}Assign"), Span::call_site());
+        let assign_fn = Ident::new(&format!("{}_assign", op.to_lowercase()), Span::call_site());
+        let (generics, rhs_ty, rhs_expr, rhs_bound): (TokenStream, Type, _, TokenStream) = match rhs
+        {
+            Rhs::Newtype => (
+                quote!(#impl_generics),
+                parse_quote!(#name #ty_generics),
+                quote!(rhs.#member),
+                quote!(),
+            ),
+            Rhs::Opposite => (
+                quote!(#impl_generics),
+                opposite.clone(),
+                quote!(rhs),
+                quote!(),
+            ),
+            Rhs::Unsigned => {
+                let mut generics = input.generics.clone();
+                generics.params.push(parse_quote!(__Rhs));
+                let (impl_generics, _, _) = generics.split_for_impl();
+                (
+                    quote!(#impl_generics),
+                    parse_quote!(__Rhs),
+                    quote!(rhs),
+                    quote!(__Rhs: ::numwit::Unsigned,),
+                )
+            }
+        };
+        let inner_rhs_ty = match rhs {
+            Rhs::Newtype => inner.clone(),
+            _ => rhs_ty.clone(),
+        };
+        let output = wrap(quote!(::core::ops::#op_trait::#op_fn(self.#member, #rhs_expr)));
+        rows.push(quote! {
+            impl #generics ::core::ops::#op_trait<#rhs_ty> for #name #ty_generics
+            where
+                #inner: ::core::ops::#op_trait<#inner_rhs_ty, Output = #inner>,
+                #rhs_bound
+                #where_preds
+            {
+                type Output = Self;
+
+                fn #op_fn(self, rhs: #rhs_ty) -> Self {
+                    #output
+                }
+            }
+
+            impl #generics ::core::ops::#assign_trait<#rhs_ty> for #name #ty_generics
+            where
+                #inner: ::core::ops::#assign_trait<#inner_rhs_ty>,
+                #rhs_bound
+                #where_preds
+            {
+                fn #assign_fn(&mut self, rhs: #rhs_ty) {
+                    ::core::ops::#assign_trait::#assign_fn(&mut self.#member, #rhs_expr)
+                }
+            }
+        });
+    };
+    match sign {
+        Sign::Positive => {
+            row("Add", Rhs::Newtype);
+            row("Add", Rhs::Unsigned);
+            row("Sub", Rhs::Opposite);
+            row("Mul", Rhs::Newtype);
+            row("Div", Rhs::Newtype);
+            row("Div", Rhs::Unsigned);
+        }
+        // The `impl Unsigned` rows aren't forwarded, because they would
+        // overlap with the `Positive` rows.
+        Sign::Negative => {
+            row("Add", Rhs::Newtype);
+            row("Sub", Rhs::Opposite);
+            row("Mul", Rhs::Opposite);
+            row("Div", Rhs::Opposite);
+        }
+    }
+
+    let mut de_generics = input.generics.clone();
+    de_generics.params.insert(0, parse_quote!('__de));
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+    let deserialized = wrap(quote!(__inner));
+
+    Ok(quote! {
+        #(#rows)*
+
+        impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
+            type Target = #inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.#member
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics
+        where
+            #inner: ::core::fmt::Display,
+            #where_preds
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.#member, f)
+            }
+        }
+
+        impl #impl_generics ::numwit::__private::serde::Serialize for #name #ty_generics
+        where
+            #inner: ::numwit::__private::serde::Serialize,
+            #where_preds
+        {
+            fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: ::numwit::__private::serde::Serializer,
+            {
+                ::numwit::__private::serde::Serialize::serialize(&self.#member, serializer)
+            }
+        }
+
+        impl #de_impl_generics ::numwit::__private::serde::Deserialize<'__de> for #name #ty_generics
+        where
+            #inner: ::numwit::__private::serde::Deserialize<'__de>,
+            #where_preds
+        {
+            fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: ::numwit::__private::serde::Deserializer<'__de>,
+            {
+                let __inner = <#inner as ::numwit::__private::serde::Deserialize<'__de>>::deserialize(deserializer)?;
+                ::core::result::Result::Ok(#deserialized)
+            }
+        }
+    })
+}
+
+#[derive(Clone, Copy)]
+enum Rhs {
+    /// The newtype itself.
+    Newtype,
+    /// The witness with the opposite sign to the newtype's field.
+    Opposite,
+    /// `impl Unsigned`.
+    Unsigned,
+}
@@ -0,0 +1,62 @@
+//! [`arbitrary::Arbitrary`] implementations which map raw bytes into the valid
+//! range, rather than rejecting out-of-range values.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Negative, Positive};
+
+macro_rules! arbitrary_positive {
+    ($($ty:ty),* $(,)?) => {$(
+        impl<'a> Arbitrary<'a> for Positive<$ty> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                u.int_in_range(1..=<$ty>::MAX).map(Self::new_unchecked)
+            }
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$ty as Arbitrary>::size_hint(depth)
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_negative {
+    ($($ty:ty),* $(,)?) => {$(
+        impl<'a> Arbitrary<'a> for Negative<$ty> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                u.int_in_range(<$ty>::MIN..=-1).map(Self::new_unchecked)
+            }
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$ty as Arbitrary>::size_hint(depth)
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_float {
+    ($($ty:ty),* $(,)?) => {$(
+        impl<'a> Arbitrary<'a> for Positive<$ty> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                // The bit patterns of the positive floats are ordered, and run
+                // from the smallest subnormal to infinity.
+                u.int_in_range(1..=<$ty>::INFINITY.to_bits())
+                    .map(<$ty>::from_bits)
+                    .map(Self::new_unchecked)
+            }
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$ty as Arbitrary>::size_hint(depth)
+            }
+        }
+
+        impl<'a> Arbitrary<'a> for Negative<$ty> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                Positive::<$ty>::arbitrary(u).map(|it| -it)
+            }
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$ty as Arbitrary>::size_hint(depth)
+            }
+        }
+    )*};
+}
+
+arbitrary_positive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+arbitrary_negative!(i8, i16, i32, i64, i128, isize);
+arbitrary_float!(f32, f64);
@@ -0,0 +1,124 @@
+use std::{
+    fmt,
+    sync::atomic::{self, Ordering},
+};
+
+use crate::Positive;
+
+/// The strongest ordering that a load may have, as part of a read-modify-write
+/// with `order`.
+fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        it => it,
+    }
+}
+
+macro_rules! atomic_positive {
+    ($($(#[doc = $doc:literal])* #[cfg($cfg:meta)] $name:ident($atomic:ident, $ty:ty);)*) => {$(
+        $(#[doc = $doc])*
+        ///
+        /// Only operations which can't yield zero are provided, so every load
+        /// yields a valid [`Positive`].
+        #[cfg($cfg)]
+        #[repr(transparent)]
+        pub struct $name(atomic::$atomic);
+
+        #[cfg($cfg)]
+        impl $name {
+            pub const fn new(value: Positive<$ty>) -> Self {
+                Self(atomic::$atomic::new(value.0))
+            }
+            pub fn into_inner(self) -> Positive<$ty> {
+                Positive::new_unchecked(self.0.into_inner())
+            }
+            pub fn get_mut(&mut self) -> &mut Positive<$ty> {
+                let it: *mut $ty = self.0.get_mut();
+                // SAFETY: `Positive` is `#[repr(transparent)]`, and every
+                // value stored is positive.
+                unsafe { &mut *it.cast() }
+            }
+            pub fn load(&self, order: Ordering) -> Positive<$ty> {
+                Positive::new_unchecked(self.0.load(order))
+            }
+            pub fn store(&self, value: Positive<$ty>, order: Ordering) {
+                self.0.store(value.0, order)
+            }
+            /// Stores `value`, returning the previous value.
+            pub fn swap(&self, value: Positive<$ty>, order: Ordering) -> Positive<$ty> {
+                Positive::new_unchecked(self.0.swap(value.0, order))
+            }
+            /// Adds `value`, returning the previous value.
+            ///
+            /// Returns [`None`], leaving the current value unchanged, if the
+            /// addition would overflow.
+            pub fn fetch_add(&self, value: Positive<$ty>, order: Ordering) -> Option<Positive<$ty>> {
+                self.0
+                    .fetch_update(order, load_ordering(order), |it| it.checked_add(value.0))
+                    .ok()
+                    .map(Positive::new_unchecked)
+            }
+            /// Stores the maximum of the current value and `value`, returning
+            /// the previous value.
+            pub fn fetch_max(&self, value: Positive<$ty>, order: Ordering) -> Positive<$ty> {
+                Positive::new_unchecked(self.0.fetch_max(value.0, order))
+            }
+            /// Stores the minimum of the current value and `value`, returning
+            /// the previous value.
+            pub fn fetch_min(&self, value: Positive<$ty>, order: Ordering) -> Positive<$ty> {
+                Positive::new_unchecked(self.0.fetch_min(value.0, order))
+            }
+            pub fn compare_exchange(
+                &self,
+                current: Positive<$ty>,
+                new: Positive<$ty>,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<Positive<$ty>, Positive<$ty>> {
+                self.0
+                    .compare_exchange(current.0, new.0, success, failure)
+                    .map(Positive::new_unchecked)
+                    .map_err(Positive::new_unchecked)
+            }
+            pub fn compare_exchange_weak(
+                &self,
+                current: Positive<$ty>,
+                new: Positive<$ty>,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<Positive<$ty>, Positive<$ty>> {
+                self.0
+                    .compare_exchange_weak(current.0, new.0, success, failure)
+                    .map(Positive::new_unchecked)
+                    .map_err(Positive::new_unchecked)
+            }
+        }
+
+        #[cfg($cfg)]
+        impl From<Positive<$ty>> for $name {
+            fn from(value: Positive<$ty>) -> Self {
+                Self::new(value)
+            }
+        }
+
+        #[cfg($cfg)]
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+    )*};
+}
+
+atomic_positive! {
+    /// A [`Positive<u32>`] which can be shared between threads.
+    #[cfg(target_has_atomic = "32")]
+    AtomicPositiveU32(AtomicU32, u32);
+    /// A [`Positive<u64>`] which can be shared between threads.
+    #[cfg(target_has_atomic = "64")]
+    AtomicPositiveU64(AtomicU64, u64);
+    /// A [`Positive<usize>`] which can be shared between threads.
+    #[cfg(target_has_atomic = "ptr")]
+    AtomicPositiveUsize(AtomicUsize, usize);
+}
@@ -0,0 +1,26 @@
+//! [`bytemuck`] implementations, which check the predicate when casting into a witness.
+
+use bytemuck::{AnyBitPattern, CheckedBitPattern, NoUninit};
+
+use crate::{Predicate, Witness};
+
+// SAFETY: `Witness` is `#[repr(transparent)]`, and every `Witness<T, P>` is a valid `T`.
+unsafe impl<T, P> NoUninit for Witness<T, P>
+where
+    T: NoUninit,
+    P: 'static,
+{
+}
+
+// SAFETY: `Witness` is `#[repr(transparent)]`, and `P` holding is exactly its invariant.
+unsafe impl<T, P> CheckedBitPattern for Witness<T, P>
+where
+    T: AnyBitPattern,
+    P: Predicate<T> + 'static,
+{
+    type Bits = T;
+
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        P::test(bits)
+    }
+}
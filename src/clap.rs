@@ -0,0 +1,149 @@
+//! [`clap`] value parsers, so that witnesses can be used as arguments.
+
+use std::{ffi::OsStr, fmt, marker::PhantomData, str::FromStr};
+
+use clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::{ContextKind, ContextValue, ErrorKind},
+    Arg, Command, Error,
+};
+
+use crate::{Negative, Positive};
+
+/// A [`TypedValueParser`] for primitive [`Positive`]s.
+#[derive(Debug)]
+pub struct PositiveValueParser<T>(PhantomData<fn() -> T>);
+
+impl<T> Clone for PositiveValueParser<T> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// A [`TypedValueParser`] for primitive [`Negative`]s.
+#[derive(Debug)]
+pub struct NegativeValueParser<T>(PhantomData<fn() -> T>);
+
+impl<T> Clone for NegativeValueParser<T> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+fn arg_name(arg: Option<&Arg>) -> String {
+    arg.map(ToString::to_string)
+        .unwrap_or_else(|| String::from("..."))
+}
+
+fn parse<T>(cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let value = value
+        .to_str()
+        .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+    value.parse().map_err(|e| {
+        Error::raw(
+            ErrorKind::ValueValidation,
+            format!("invalid value '{value}' for '{}': {e}\n", arg_name(arg)),
+        )
+        .with_cmd(cmd)
+    })
+}
+
+fn wrong_sign(
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &OsStr,
+    constraint: &str,
+    nearest: Option<impl fmt::Debug>,
+) -> Error {
+    let mut e = Error::new(ErrorKind::ValueValidation).with_cmd(cmd);
+    e.insert(ContextKind::InvalidArg, ContextValue::String(arg_name(arg)));
+    e.insert(
+        ContextKind::InvalidValue,
+        ContextValue::String(value.to_string_lossy().into_owned()),
+    );
+    let mut suggested = vec![format!("the value must be {constraint}").into()];
+    // Floats have no useful nearest value, since it would be subnormal.
+    if let Some(nearest) = nearest {
+        suggested.push(format!("the nearest valid value is '{nearest:?}'").into());
+    }
+    e.insert(ContextKind::Suggested, ContextValue::StyledStrs(suggested));
+    e
+}
+
+/// The nearest valid value to suggest, if any.
+macro_rules! nearest {
+    ($ty:ty) => {
+        None::<$ty>
+    };
+    ($ty:ty => $nearest:expr) => {
+        Some::<$ty>($nearest)
+    };
+}
+
+macro_rules! value_parser_positive {
+    ($($ty:ty $(=> $nearest:expr)?),* $(,)?) => {$(
+        impl ValueParserFactory for Positive<$ty> {
+            type Parser = PositiveValueParser<$ty>;
+
+            fn value_parser() -> Self::Parser {
+                PositiveValueParser(PhantomData)
+            }
+        }
+
+        impl TypedValueParser for PositiveValueParser<$ty> {
+            type Value = Positive<$ty>;
+
+            fn parse_ref(
+                &self,
+                cmd: &Command,
+                arg: Option<&Arg>,
+                value: &OsStr,
+            ) -> Result<Self::Value, Error> {
+                let nearest = nearest!($ty $(=> $nearest)?);
+                Positive::new(parse::<$ty>(cmd, arg, value)?)
+                    .map_err(|_| wrong_sign(cmd, arg, value, "positive (greater than 0)", nearest))
+            }
+        }
+    )*};
+}
+
+macro_rules! value_parser_negative {
+    ($($ty:ty $(=> $nearest:expr)?),* $(,)?) => {$(
+        impl ValueParserFactory for Negative<$ty> {
+            type Parser = NegativeValueParser<$ty>;
+
+            fn value_parser() -> Self::Parser {
+                NegativeValueParser(PhantomData)
+            }
+        }
+
+        impl TypedValueParser for NegativeValueParser<$ty> {
+            type Value = Negative<$ty>;
+
+            fn parse_ref(
+                &self,
+                cmd: &Command,
+                arg: Option<&Arg>,
+                value: &OsStr,
+            ) -> Result<Self::Value, Error> {
+                let nearest = nearest!($ty $(=> $nearest)?);
+                Negative::new(parse::<$ty>(cmd, arg, value)?)
+                    .map_err(|_| wrong_sign(cmd, arg, value, "negative (less than 0)", nearest))
+            }
+        }
+    )*};
+}
+
+value_parser_positive!(
+    u8 => 1, u16 => 1, u32 => 1, u64 => 1, u128 => 1, usize => 1,
+    i8 => 1, i16 => 1, i32 => 1, i64 => 1, i128 => 1, isize => 1,
+    f32, f64,
+);
+value_parser_negative!(
+    i8 => -1, i16 => -1, i32 => -1, i64 => -1, i128 => -1, isize => -1,
+    f32, f64,
+);
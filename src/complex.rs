@@ -0,0 +1,69 @@
+use std::fmt;
+
+use num::{Complex, Float};
+
+use crate::{
+    Describe, Given, Implies, IsNegative, IsPositive, Positive, Predicate, Rejected, Witness,
+};
+
+/// A [`Predicate`] that a number isn't zero, or NaN.
+///
+/// [`IsPositive`] and [`IsNegative`] [`Implies`] this.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsNonZero;
+
+impl Describe for IsNonZero {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NonZero")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("non-zero")
+    }
+}
+
+impl<T> Predicate<T> for IsNonZero
+where
+    T: num::Zero + PartialEq,
+{
+    #[allow(clippy::eq_op)] // NaN isn't equal to itself.
+    fn test(value: &T) -> bool {
+        value == value && !value.is_zero()
+    }
+}
+
+impl Implies<IsNonZero, Given> for IsPositive {}
+
+impl Implies<IsNonZero, Given> for IsNegative {}
+
+/// A guarantee that a [`Complex`] number isn't zero, so has a [`Positive`]
+/// magnitude.
+pub type NonZeroComplex<F> = Witness<Complex<F>, IsNonZero>;
+
+pub type NotNonZeroComplex<F> = Rejected<Complex<F>, IsNonZero>;
+
+impl<F: Float> NonZeroComplex<F> {
+    /// `norm * (cos(arg) + i sin(arg))`.
+    ///
+    /// Fails if a part is NaN, such as when `arg` isn't finite.
+    pub fn from_polar(norm: Positive<F>, arg: F) -> Result<Self, NotNonZeroComplex<F>> {
+        Self::new(Complex::from_polar(norm.0, arg))
+    }
+    /// The magnitude, `sqrt(re^2 + im^2)`.
+    pub fn norm(&self) -> Positive<F> {
+        Positive::new_unchecked(self.0.norm())
+    }
+    /// The squared magnitude, `re^2 + im^2`.
+    ///
+    /// Returns [`None`] if it underflows to zero, such as for `1e-200`.
+    pub fn norm_sqr(&self) -> Option<Positive<F>> {
+        Positive::new(self.0.norm_sqr()).ok()
+    }
+    /// The angle from the positive real axis, in `(-pi, pi]`.
+    pub fn arg(&self) -> F {
+        self.0.arg()
+    }
+    /// `(self.norm(), self.arg())`.
+    pub fn to_polar(&self) -> (Positive<F>, F) {
+        (self.norm(), self.arg())
+    }
+}
@@ -0,0 +1,188 @@
+//! Predicates built from other predicates.
+
+use std::{fmt, marker::PhantomData};
+
+use crate::{Describe, IsNegative, IsPositive, Negative, Positive, Predicate, Rejected, Witness};
+
+/// A [`Predicate`] that both `A` and `B` hold.
+///
+/// ```
+/// use numwit::{And, IsPositive, LessThan, Witness};
+///
+/// let n = Witness::<u32, And<IsPositive, LessThan<1000>>>::new(10).unwrap();
+/// let _sum = *n.as_positive() + *n.as_positive();
+///
+/// let e = Witness::<u32, And<IsPositive, LessThan<1000>>>::new(5000).unwrap_err();
+/// assert_eq!(e.to_string(), "The value 5000 was not less than 1000");
+/// ```
+pub struct And<A, B>(PhantomData<fn() -> (A, B)>);
+
+/// A [`Predicate`] that either `A` or `B` hold.
+pub struct Or<A, B>(PhantomData<fn() -> (A, B)>);
+
+/// A [`Predicate`] that `A` doesn't hold.
+pub struct Not<A>(PhantomData<fn() -> A>);
+
+impl<A: Describe, B: Describe> Describe for And<A, B> {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("And<")?;
+        A::name(f)?;
+        f.write_str(", ")?;
+        B::name(f)?;
+        f.write_str(">")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        A::description(f)?;
+        f.write_str(" and ")?;
+        B::description(f)
+    }
+}
+
+impl<T, A, B> Predicate<T> for And<A, B>
+where
+    A: Predicate<T>,
+    B: Predicate<T>,
+{
+    fn test(value: &T) -> bool {
+        A::test(value) && B::test(value)
+    }
+    /// The reason from whichever of `A` or `B` didn't hold.
+    fn reason(value: &T) -> fn(&mut fmt::Formatter<'_>) -> fmt::Result {
+        match A::test(value) {
+            true => B::reason(value),
+            false => A::reason(value),
+        }
+    }
+}
+
+impl<A: Describe, B: Describe> Describe for Or<A, B> {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Or<")?;
+        A::name(f)?;
+        f.write_str(", ")?;
+        B::name(f)?;
+        f.write_str(">")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        A::description(f)?;
+        f.write_str(" or ")?;
+        B::description(f)
+    }
+}
+
+impl<T, A, B> Predicate<T> for Or<A, B>
+where
+    A: Predicate<T>,
+    B: Predicate<T>,
+{
+    fn test(value: &T) -> bool {
+        A::test(value) || B::test(value)
+    }
+}
+
+impl<A: Describe> Describe for Not<A> {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Not<")?;
+        A::name(f)?;
+        f.write_str(">")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("non-")?;
+        A::description(f)
+    }
+}
+
+impl<T, A> Predicate<T> for Not<A>
+where
+    A: Predicate<T>,
+{
+    fn test(value: &T) -> bool {
+        !A::test(value)
+    }
+}
+
+/////////////////
+// Implication //
+/////////////////
+
+/// Whenever `Self` holds, so does `Q`.
+///
+/// `Ix` is inferred, and locates `Q` within `Self`.
+/// Implement it with the [`Given`] index for a predicate which implies another
+/// by definition.
+pub trait Implies<Q, Ix> {}
+
+/// [`Implies`] index: a predicate implies itself.
+pub struct Here;
+/// [`Implies`] index: a predicate implies another by definition, such as
+/// [`IsOpenUnit`](crate::IsOpenUnit) implying [`IsPositive`].
+pub struct Given;
+/// [`Implies`] index: an [`And`] implies whatever its left side does.
+pub struct Left<Ix>(PhantomData<Ix>);
+/// [`Implies`] index: an [`And`] implies whatever its right side does.
+pub struct Right<Ix>(PhantomData<Ix>);
+/// [`Implies`] index: whatever implies `A` implies `Or<A, B>`.
+pub struct OrLeft<Ix>(PhantomData<Ix>);
+/// [`Implies`] index: whatever implies `B` implies `Or<A, B>`.
+pub struct OrRight<Ix>(PhantomData<Ix>);
+
+impl<P> Implies<P, Here> for P {}
+
+impl<A, B, Q, Ix> Implies<Q, Left<Ix>> for And<A, B> where A: Implies<Q, Ix> {}
+
+impl<A, B, Q, Ix> Implies<Q, Right<Ix>> for And<A, B> where B: Implies<Q, Ix> {}
+
+impl<P, A, B, Ix> Implies<Or<A, B>, OrLeft<Ix>> for P where P: Implies<A, Ix> {}
+
+impl<P, A, B, Ix> Implies<Or<A, B>, OrRight<Ix>> for P where P: Implies<B, Ix> {}
+
+impl<T, P> Witness<T, P> {
+    /// Forget part of the guarantee.
+    ///
+    /// ```
+    /// use numwit::{And, IsPositive, LessThan, Positive, Witness};
+    ///
+    /// let n = Witness::<i32, And<IsPositive, LessThan<1000>>>::new(10).unwrap();
+    /// let n: Positive<i32> = n.weaken();
+    /// ```
+    pub fn weaken<Q, Ix>(self) -> Witness<T, Q>
+    where
+        P: Implies<Q, Ix>,
+    {
+        Witness::new_unchecked(self.0)
+    }
+    /// View this as a witness with a weaker guarantee.
+    pub fn project<Q, Ix>(&self) -> &Witness<T, Q>
+    where
+        P: Implies<Q, Ix>,
+    {
+        // SAFETY: `Witness` is `#[repr(transparent)]`, and `Q` holds whenever `P` does.
+        unsafe { &*(self as *const Self as *const Witness<T, Q>) }
+    }
+    pub fn as_positive<Ix>(&self) -> &Positive<T>
+    where
+        P: Implies<IsPositive, Ix>,
+    {
+        self.project()
+    }
+    pub fn as_negative<Ix>(&self) -> &Negative<T>
+    where
+        P: Implies<IsNegative, Ix>,
+    {
+        self.project()
+    }
+    /// Additionally check `Q`.
+    ///
+    /// ```
+    /// use numwit::{LessThan, Positive};
+    ///
+    /// let n = Positive::new(10).unwrap().and::<LessThan<1000>>().unwrap();
+    /// assert!(Positive::new(5000).unwrap().and::<LessThan<1000>>().is_err());
+    /// ```
+    pub fn and<Q>(self) -> Result<Witness<T, And<P, Q>>, Rejected<T, Q>>
+    where
+        Q: Predicate<T>,
+    {
+        Witness::<T, Q>::new(self.0).map(|it| Witness::new_unchecked(it.0))
+    }
+}
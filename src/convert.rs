@@ -0,0 +1,121 @@
+//! Conversions between witnesses of different primitive integers.
+//!
+//! The sign is already known, so a conversion only fails if the value doesn't
+//! fit.
+
+use std::num::TryFromIntError;
+
+use crate::{Negative, Positive};
+
+macro_rules! convert {
+    (From<$W:ident> { $($from:ty => $($to:ty),*;)* }) => {$($(
+        impl From<$W<$from>> for $W<$to> {
+            fn from(value: $W<$from>) -> Self {
+                Self::new_unchecked(value.0.into())
+            }
+        }
+    )*)*};
+    // The value is positive, so the cast is lossless.
+    (From<Positive> as { $($from:ty => $($to:ty),*;)* }) => {$($(
+        impl From<Positive<$from>> for Positive<$to> {
+            fn from(value: Positive<$from>) -> Self {
+                Self::new_unchecked(value.0 as $to)
+            }
+        }
+    )*)*};
+    (TryFrom<$W:ident> { $($from:ty => $($to:ty),*;)* }) => {$($(
+        impl TryFrom<$W<$from>> for $W<$to> {
+            type Error = TryFromIntError;
+
+            fn try_from(value: $W<$from>) -> Result<Self, Self::Error> {
+                <$to>::try_from(value.0).map(Self::new_unchecked)
+            }
+        }
+    )*)*};
+}
+
+convert!(From<Positive> {
+    u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize;
+    u16 => u32, u64, u128, usize, i32, i64, i128;
+    u32 => u64, u128, i64, i128;
+    u64 => u128, i128;
+    i8 => i16, i32, i64, i128, isize;
+    i16 => i32, i64, i128, isize;
+    i32 => i64, i128;
+    i64 => i128;
+});
+
+convert!(From<Positive> as {
+    i8 => u8, u16, u32, u64, u128, usize;
+    i16 => u16, u32, u64, u128, usize;
+    i32 => u32, u64, u128;
+    i64 => u64, u128;
+    i128 => u128;
+    isize => usize;
+});
+
+// `isize` may be 16 bits, so std only widens `u8` and `i16` into it.
+convert!(TryFrom<Positive> {
+    u8 => i8;
+    u16 => u8, i8, i16, isize;
+    u32 => u8, u16, usize, i8, i16, i32, isize;
+    u64 => u8, u16, u32, usize, i8, i16, i32, i64, isize;
+    u128 => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize;
+    usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize;
+    i16 => u8, i8;
+    i32 => u8, u16, usize, i8, i16, isize;
+    i64 => u8, u16, u32, usize, i8, i16, i32, isize;
+    i128 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize;
+    isize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128;
+});
+
+convert!(From<Negative> {
+    i8 => i16, i32, i64, i128, isize;
+    i16 => i32, i64, i128, isize;
+    i32 => i64, i128;
+    i64 => i128;
+});
+
+convert!(TryFrom<Negative> {
+    i16 => i8;
+    i32 => i8, i16, isize;
+    i64 => i8, i16, i32, isize;
+    i128 => i8, i16, i32, i64, isize;
+    isize => i8, i16, i32, i64, i128;
+});
+
+macro_rules! sign {
+    ($($signed:ty, $unsigned:ty);* $(;)?) => {$(
+        impl Positive<$signed> {
+            /// The same value, which always fits in
+            #[doc = concat!("[`", stringify!($unsigned), "`].")]
+            pub fn unsigned_abs(self) -> Positive<$unsigned> {
+                Positive::new_unchecked(self.0.unsigned_abs())
+            }
+        }
+
+        impl Negative<$signed> {
+            /// The magnitude, which is representable even for
+            #[doc = concat!("[`", stringify!($signed), "::MIN`].")]
+            pub fn unsigned_abs(self) -> Positive<$unsigned> {
+                Positive::new_unchecked(self.0.unsigned_abs())
+            }
+        }
+
+        impl Positive<$unsigned> {
+            /// Returns [`None`] if `self` is too large.
+            pub fn to_signed(self) -> Option<Positive<$signed>> {
+                <$signed>::try_from(self.0).ok().map(Positive::new_unchecked)
+            }
+        }
+    )*};
+}
+
+sign! {
+    i8, u8;
+    i16, u16;
+    i32, u32;
+    i64, u64;
+    i128, u128;
+    isize, usize;
+}
@@ -0,0 +1,77 @@
+//! [`diesel`] implementations, which use the SQL representation of `T`.
+//!
+//! [`FromSqlRow`](diesel::deserialize::FromSqlRow) is provided through
+//! [`Queryable`], as `#[derive(FromSqlRow)]` would.
+//! Deserializing fails with [`Rejected`](crate::Rejected) if the predicate
+//! doesn't hold for a column.
+
+use std::fmt;
+
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, Queryable},
+    expression::{AsExpression, TypedExpressionType},
+    serialize::{self, Output, ToSql},
+    sql_types::{SingleValue, SqlType},
+};
+
+use crate::{Describe, Predicate, Witness};
+
+impl<ST, DB, T, P> FromSql<ST, DB> for Witness<T, P>
+where
+    DB: Backend,
+    T: FromSql<ST, DB> + fmt::Display + fmt::Debug + Send + Sync + 'static,
+    P: Predicate<T> + 'static,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::new(T::from_sql(bytes)?)?)
+    }
+}
+
+impl<ST, DB, T, P> Queryable<ST, DB> for Witness<T, P>
+where
+    DB: Backend,
+    ST: SingleValue,
+    Self: FromSql<ST, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl<ST, DB, T, P> ToSql<ST, DB> for Witness<T, P>
+where
+    DB: Backend,
+    T: ToSql<ST, DB>,
+    P: Describe,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+impl<ST, T, P> AsExpression<ST> for Witness<T, P>
+where
+    ST: SqlType + TypedExpressionType,
+    T: AsExpression<ST>,
+{
+    type Expression = T::Expression;
+
+    fn as_expression(self) -> Self::Expression {
+        self.0.as_expression()
+    }
+}
+
+impl<'a, ST, T, P> AsExpression<ST> for &'a Witness<T, P>
+where
+    ST: SqlType + TypedExpressionType,
+    &'a T: AsExpression<ST>,
+{
+    type Expression = <&'a T as AsExpression<ST>>::Expression;
+
+    fn as_expression(self) -> Self::Expression {
+        self.inner().as_expression()
+    }
+}
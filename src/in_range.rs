@@ -0,0 +1,338 @@
+use std::fmt;
+
+use crate::{Describe, Negative, Positive, Predicate, Rejected, Witness};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Primitive integers, which can be used in an [`InRange`].
+pub trait RangeInt: num::PrimInt + fmt::Display + sealed::Sealed {
+    /// `Self::MIN`, saturated to an [`i128`].
+    const LOWEST: i128;
+    /// `Self::MAX`, saturated to an [`i128`].
+    const HIGHEST: i128;
+}
+
+macro_rules! range_int {
+    ($($ty:ty),* $(,)?) => {$(
+        impl sealed::Sealed for $ty {}
+        impl RangeInt for $ty {
+            const LOWEST: i128 = <$ty>::MIN as i128;
+            const HIGHEST: i128 = match (<$ty>::MAX as u128) > i128::MAX as u128 {
+                true => i128::MAX,
+                false => <$ty>::MAX as i128,
+            };
+        }
+    )*};
+}
+
+range_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A [`Predicate`] that `MIN <= T <= MAX`, for primitive integers.
+///
+/// The bounds are [`i128`]s, rather than `T`s, because const generics may not
+/// depend on other generic parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsInRange<const MIN: i128, const MAX: i128>;
+
+impl<const MIN: i128, const MAX: i128> Describe for IsInRange<MIN, MAX> {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("InRange<{MIN}, {MAX}>"))
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("in the range {MIN}..={MAX}"))
+    }
+}
+
+impl<T: RangeInt, const MIN: i128, const MAX: i128> Predicate<T> for IsInRange<MIN, MAX> {
+    fn test(value: &T) -> bool {
+        const { assert!(MIN <= MAX, "empty range") };
+        value.to_i128().is_some_and(|it| MIN <= it && it <= MAX)
+    }
+}
+
+/// A guarantee that `MIN <= T <= MAX`.
+pub type InRange<T, const MIN: i128, const MAX: i128> = Witness<T, IsInRange<MIN, MAX>>;
+
+pub type NotInRange<T, const MIN: i128, const MAX: i128> = Rejected<T, IsInRange<MIN, MAX>>;
+
+impl<T, const MIN: i128, const MAX: i128> InRange<T, MIN, MAX>
+where
+    T: RangeInt,
+{
+    pub fn checked_add<const RHS_MIN: i128, const RHS_MAX: i128>(
+        self,
+        rhs: InRange<T, RHS_MIN, RHS_MAX>,
+    ) -> Option<Self> {
+        Self::new(self.0.checked_add(&rhs.0)?).ok()
+    }
+    pub fn checked_sub<const RHS_MIN: i128, const RHS_MAX: i128>(
+        self,
+        rhs: InRange<T, RHS_MIN, RHS_MAX>,
+    ) -> Option<Self> {
+        Self::new(self.0.checked_sub(&rhs.0)?).ok()
+    }
+    pub fn checked_mul<const RHS_MIN: i128, const RHS_MAX: i128>(
+        self,
+        rhs: InRange<T, RHS_MIN, RHS_MAX>,
+    ) -> Option<Self> {
+        Self::new(self.0.checked_mul(&rhs.0)?).ok()
+    }
+    /// Add, returning a witness whose bounds are inferred from context.
+    ///
+    /// ```
+    /// # use numwit::InRange;
+    /// let n = InRange::<u8, 1, 10>::new(10).unwrap();
+    /// let sum: InRange<u8, 2, 20> = n.widening_add(n);
+    /// assert_eq!(sum, 20);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use numwit::InRange;
+    /// let n = InRange::<u8, 1, 10>::new(10).unwrap();
+    /// let sum: InRange<u8, 2, 19> = n.widening_add(n);
+    /// ```
+    ///
+    /// # Compile-time errors
+    /// - If `OUT_MIN..=OUT_MAX` doesn't contain every possible sum.
+    /// - If `T` can't represent every possible sum.
+    pub fn widening_add<
+        const RHS_MIN: i128,
+        const RHS_MAX: i128,
+        const OUT_MIN: i128,
+        const OUT_MAX: i128,
+    >(
+        self,
+        rhs: InRange<T, RHS_MIN, RHS_MAX>,
+    ) -> InRange<T, OUT_MIN, OUT_MAX> {
+        const {
+            let (lhs_min, lhs_max) = clamp(MIN, MAX, T::LOWEST, T::HIGHEST);
+            let (rhs_min, rhs_max) = clamp(RHS_MIN, RHS_MAX, T::LOWEST, T::HIGHEST);
+            let (min, max) = (add(lhs_min, rhs_min), add(lhs_max, rhs_max));
+            assert_widened::<OUT_MIN, OUT_MAX>(min, max, T::LOWEST, T::HIGHEST);
+        };
+        InRange::new_unchecked(self.0 + rhs.0)
+    }
+    /// Subtract, returning a witness whose bounds are inferred from context.
+    ///
+    /// # Compile-time errors
+    /// - If `OUT_MIN..=OUT_MAX` doesn't contain every possible difference.
+    /// - If `T` can't represent every possible difference.
+    pub fn widening_sub<
+        const RHS_MIN: i128,
+        const RHS_MAX: i128,
+        const OUT_MIN: i128,
+        const OUT_MAX: i128,
+    >(
+        self,
+        rhs: InRange<T, RHS_MIN, RHS_MAX>,
+    ) -> InRange<T, OUT_MIN, OUT_MAX> {
+        const {
+            let (lhs_min, lhs_max) = clamp(MIN, MAX, T::LOWEST, T::HIGHEST);
+            let (rhs_min, rhs_max) = clamp(RHS_MIN, RHS_MAX, T::LOWEST, T::HIGHEST);
+            let (min, max) = (sub(lhs_min, rhs_max), sub(lhs_max, rhs_min));
+            assert_widened::<OUT_MIN, OUT_MAX>(min, max, T::LOWEST, T::HIGHEST);
+        };
+        InRange::new_unchecked(self.0 - rhs.0)
+    }
+    /// Multiply, returning a witness whose bounds are inferred from context.
+    ///
+    /// # Compile-time errors
+    /// - If `OUT_MIN..=OUT_MAX` doesn't contain every possible product.
+    /// - If `T` can't represent every possible product.
+    pub fn widening_mul<
+        const RHS_MIN: i128,
+        const RHS_MAX: i128,
+        const OUT_MIN: i128,
+        const OUT_MAX: i128,
+    >(
+        self,
+        rhs: InRange<T, RHS_MIN, RHS_MAX>,
+    ) -> InRange<T, OUT_MIN, OUT_MAX> {
+        const {
+            let (lhs_min, lhs_max) = clamp(MIN, MAX, T::LOWEST, T::HIGHEST);
+            let (rhs_min, rhs_max) = clamp(RHS_MIN, RHS_MAX, T::LOWEST, T::HIGHEST);
+            let corners = [
+                mul(lhs_min, rhs_min),
+                mul(lhs_min, rhs_max),
+                mul(lhs_max, rhs_min),
+                mul(lhs_max, rhs_max),
+            ];
+            let (mut min, mut max) = (corners[0], corners[0]);
+            let mut ix = 1;
+            while ix < corners.len() {
+                if corners[ix] < min {
+                    min = corners[ix]
+                }
+                if corners[ix] > max {
+                    max = corners[ix]
+                }
+                ix += 1;
+            }
+            assert_widened::<OUT_MIN, OUT_MAX>(min, max, T::LOWEST, T::HIGHEST);
+        };
+        InRange::new_unchecked(self.0 * rhs.0)
+    }
+}
+
+/// The values that a `T` in `min..=max` may actually take.
+const fn clamp(min: i128, max: i128, lowest: i128, highest: i128) -> (i128, i128) {
+    (
+        if min < lowest { lowest } else { min },
+        if max > highest { highest } else { max },
+    )
+}
+
+const fn add(lhs: i128, rhs: i128) -> i128 {
+    match lhs.checked_add(rhs) {
+        Some(it) => it,
+        None => panic!("bounds overflow an i128"),
+    }
+}
+
+const fn sub(lhs: i128, rhs: i128) -> i128 {
+    match lhs.checked_sub(rhs) {
+        Some(it) => it,
+        None => panic!("bounds overflow an i128"),
+    }
+}
+
+const fn mul(lhs: i128, rhs: i128) -> i128 {
+    match lhs.checked_mul(rhs) {
+        Some(it) => it,
+        None => panic!("bounds overflow an i128"),
+    }
+}
+
+const fn assert_widened<const OUT_MIN: i128, const OUT_MAX: i128>(
+    min: i128,
+    max: i128,
+    lowest: i128,
+    highest: i128,
+) {
+    assert!(lowest <= min && max <= highest, "the result overflows `T`");
+    assert!(
+        OUT_MIN <= min && max <= OUT_MAX,
+        "the output range is too narrow"
+    );
+}
+
+////////////////
+// Predicates //
+////////////////
+
+/// A [`Predicate`] that `T < N`, for primitive integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct LessThan<const N: i128>;
+
+/// A [`Predicate`] that `T > N`, for primitive integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct GreaterThan<const N: i128>;
+
+impl<const N: i128> Describe for LessThan<N> {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("LessThan<{N}>"))
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("less than {N}"))
+    }
+}
+
+impl<T: RangeInt, const N: i128> Predicate<T> for LessThan<N> {
+    fn test(value: &T) -> bool {
+        // Values which don't fit in an `i128` are greater than any `N`.
+        value.to_i128().is_some_and(|it| it < N)
+    }
+}
+
+impl<const N: i128> Describe for GreaterThan<N> {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("GreaterThan<{N}>"))
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("greater than {N}"))
+    }
+}
+
+impl<T: RangeInt, const N: i128> Predicate<T> for GreaterThan<N> {
+    fn test(value: &T) -> bool {
+        value.to_i128().is_none_or(|it| it > N)
+    }
+}
+
+/////////////////
+// Conversions //
+/////////////////
+
+impl<T, const MIN: i128, const MAX: i128> InRange<T, MIN, MAX>
+where
+    T: RangeInt,
+{
+    /// Returns [`None`] if `self` isn't positive, which never happens if
+    /// `MIN > 0`.
+    pub fn positive(self) -> Option<Positive<T>> {
+        match MIN > 0 {
+            true => Some(Positive::new_unchecked(self.0)),
+            false => Positive::new(self.0).ok(),
+        }
+    }
+    /// Returns [`None`] if `self` isn't negative, which never happens if
+    /// `MAX < 0`.
+    pub fn negative(self) -> Option<Negative<T>> {
+        match MAX < 0 {
+            true => Some(Negative::new_unchecked(self.0)),
+            false => Negative::new(self.0).ok(),
+        }
+    }
+    /// Like [`positive`](Self::positive), for ranges which are all positive.
+    ///
+    /// ```
+    /// # use numwit::InRange;
+    /// let n = InRange::<u8, 1, 10>::new(5).unwrap();
+    /// assert_eq!(n.into_positive(), 5);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use numwit::InRange;
+    /// let n = InRange::<u8, 0, 10>::new(5).unwrap();
+    /// n.into_positive();
+    /// ```
+    ///
+    /// # Compile-time errors
+    /// - If `MIN <= 0`.
+    pub fn into_positive(self) -> Positive<T> {
+        const { assert!(MIN > 0, "range isn't all positive") };
+        Positive::new_unchecked(self.0)
+    }
+    /// Like [`negative`](Self::negative), for ranges which are all negative.
+    ///
+    /// # Compile-time errors
+    /// - If `MAX >= 0`.
+    pub fn into_negative(self) -> Negative<T> {
+        const { assert!(MAX < 0, "range isn't all negative") };
+        Negative::new_unchecked(self.0)
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> TryFrom<Positive<T>> for InRange<T, MIN, MAX>
+where
+    T: RangeInt,
+{
+    type Error = NotInRange<T, MIN, MAX>;
+
+    fn try_from(value: Positive<T>) -> Result<Self, Self::Error> {
+        Self::new(value.into_inner())
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> TryFrom<Negative<T>> for InRange<T, MIN, MAX>
+where
+    T: RangeInt,
+{
+    type Error = NotInRange<T, MIN, MAX>;
+
+    fn try_from(value: Negative<T>) -> Result<Self, Self::Error> {
+        Self::new(value.into_inner())
+    }
+}
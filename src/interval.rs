@@ -0,0 +1,324 @@
+use std::{fmt, ops};
+
+use crate::{Negative, Positive};
+
+/// A closed interval `[lo, hi]`, where `lo <= hi`.
+///
+/// Intervals of witnesses, like `Interval<Positive<T>>`, have proven signs,
+/// which arithmetic preserves according to the operation table.
+///
+/// Float arithmetic rounds each bound to nearest, so a result may not contain
+/// every exact result.
+/// Call `round_outward` after each operation to keep them.
+///
+/// Float arithmetic on infinite bounds may produce NaN bounds, such as
+/// `[inf, inf] - [inf, inf]` or `[0, 0] * [inf, inf]`, which aren't ordered,
+/// so `lo <= hi` no longer holds.
+///
+/// ```
+/// use numwit::{Interval, Positive};
+///
+/// let a = Interval::positive(Positive::new(1.0).unwrap(), Positive::new(2.0).unwrap()).unwrap();
+/// let _b: Interval<Positive<f64>> = a * a / a + a;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+/// The sign of an [`Interval`], from [`Interval::classify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalSign<T> {
+    Positive(Interval<Positive<T>>),
+    Negative(Interval<Negative<T>>),
+    ContainsZero(Interval<T>),
+}
+
+impl<T> Interval<T> {
+    pub fn lo(&self) -> &T {
+        &self.lo
+    }
+    pub fn hi(&self) -> &T {
+        &self.hi
+    }
+    /// `(lo, hi)`.
+    pub fn into_inner(self) -> (T, T) {
+        (self.lo, self.hi)
+    }
+}
+
+impl<T: Clone> Interval<T> {
+    /// `[value, value]`.
+    pub fn point(value: T) -> Self {
+        Self {
+            lo: value.clone(),
+            hi: value,
+        }
+    }
+}
+
+impl<T: PartialOrd> Interval<T> {
+    /// Returns [`None`] if `lo > hi`, or they can't be compared.
+    pub fn new(lo: T, hi: T) -> Option<Self> {
+        match lo <= hi {
+            true => Some(Self { lo, hi }),
+            false => None,
+        }
+    }
+    pub fn contains(&self, value: &T) -> bool {
+        self.lo <= *value && *value <= self.hi
+    }
+    /// The smallest interval which contains both `self` and `other`.
+    pub fn hull(self, other: Self) -> Self {
+        Self {
+            lo: min(self.lo, other.lo),
+            hi: max(self.hi, other.hi),
+        }
+    }
+    /// The interval which both `self` and `other` contain, if any.
+    pub fn intersect(self, other: Self) -> Option<Self> {
+        Self::new(max(self.lo, other.lo), min(self.hi, other.hi))
+    }
+}
+
+impl<T: PartialOrd> Interval<Positive<T>> {
+    pub fn positive(lo: Positive<T>, hi: Positive<T>) -> Option<Self> {
+        Self::new(lo, hi)
+    }
+}
+
+impl<T: PartialOrd> Interval<Negative<T>> {
+    pub fn negative(lo: Negative<T>, hi: Negative<T>) -> Option<Self> {
+        Self::new(lo, hi)
+    }
+}
+
+impl<T: num::Zero + PartialOrd> Interval<T> {
+    pub fn classify(self) -> IntervalSign<T> {
+        let Self { lo, hi } = self;
+        match (Positive::new(lo), Negative::new(hi)) {
+            (Ok(lo), Err(hi)) => IntervalSign::Positive(Interval {
+                lo,
+                hi: Positive::new_unchecked(hi.into_inner()),
+            }),
+            (Err(lo), Ok(hi)) => IntervalSign::Negative(Interval {
+                lo: Negative::new_unchecked(lo.into_inner()),
+                hi,
+            }),
+            (lo, hi) => IntervalSign::ContainsZero(Interval {
+                lo: lo.map_or_else(|it| it.into_inner(), Positive::into_inner),
+                hi: hi.map_or_else(|it| it.into_inner(), Negative::into_inner),
+            }),
+        }
+    }
+}
+
+impl<T> From<Interval<Positive<T>>> for Interval<T> {
+    fn from(value: Interval<Positive<T>>) -> Self {
+        Self {
+            lo: value.lo.into_inner(),
+            hi: value.hi.into_inner(),
+        }
+    }
+}
+
+impl<T> From<Interval<Negative<T>>> for Interval<T> {
+    fn from(value: Interval<Negative<T>>) -> Self {
+        Self {
+            lo: value.lo.into_inner(),
+            hi: value.hi.into_inner(),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    match b < a {
+        true => b,
+        false => a,
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    match b > a {
+        true => b,
+        false => a,
+    }
+}
+
+/// The smallest and largest of `products`.
+fn bounds<T: Clone + PartialOrd>(products: [T; 4]) -> Interval<T> {
+    let [a, b, c, d] = products;
+    Interval {
+        lo: min(min(a.clone(), b.clone()), min(c.clone(), d.clone())),
+        hi: max(max(a, b), max(c, d)),
+    }
+}
+
+////////////////
+// Arithmetic //
+////////////////
+
+impl<L, R, O> ops::Add<Interval<R>> for Interval<L>
+where
+    L: ops::Add<R, Output = O>,
+{
+    type Output = Interval<O>;
+
+    fn add(self, rhs: Interval<R>) -> Self::Output {
+        Interval {
+            lo: self.lo + rhs.lo,
+            hi: self.hi + rhs.hi,
+        }
+    }
+}
+
+impl<L, R, O> ops::Sub<Interval<R>> for Interval<L>
+where
+    L: ops::Sub<R, Output = O>,
+{
+    type Output = Interval<O>;
+
+    fn sub(self, rhs: Interval<R>) -> Self::Output {
+        Interval {
+            lo: self.lo - rhs.hi,
+            hi: self.hi - rhs.lo,
+        }
+    }
+}
+
+impl<L, R, O> ops::Mul<Interval<R>> for Interval<L>
+where
+    L: Clone + ops::Mul<R, Output = O>,
+    R: Clone,
+    O: Clone + PartialOrd,
+{
+    type Output = Interval<O>;
+
+    fn mul(self, rhs: Interval<R>) -> Self::Output {
+        bounds([
+            self.lo.clone() * rhs.lo.clone(),
+            self.lo * rhs.hi.clone(),
+            self.hi.clone() * rhs.lo,
+            self.hi * rhs.hi,
+        ])
+    }
+}
+
+// The divisor can't contain zero, so is an interval of `Positive` or
+// `Negative` witnesses, which the bounds of `self` must divide according to the
+// operation table.
+macro_rules! div {
+    ($($W:ident),*) => {$(
+        impl<L, R, O> ops::Div<Interval<$W<R>>> for Interval<L>
+        where
+            L: Clone + ops::Div<$W<R>, Output = O>,
+            R: Clone,
+            O: Clone + PartialOrd,
+        {
+            type Output = Interval<O>;
+
+            fn div(self, rhs: Interval<$W<R>>) -> Self::Output {
+                bounds([
+                    self.lo.clone() / rhs.lo.clone(),
+                    self.lo / rhs.hi.clone(),
+                    self.hi.clone() / rhs.lo,
+                    self.hi / rhs.hi,
+                ])
+            }
+        }
+    )*};
+}
+
+div!(Positive, Negative);
+
+impl<T> Interval<T>
+where
+    T: Clone + PartialOrd + ops::Div<Output = T>,
+{
+    /// `self / rhs`, for a dividend without a witness, such as
+    /// [`IntervalSign::ContainsZero`].
+    pub fn div_positive(self, rhs: Interval<Positive<T>>) -> Self {
+        self.div_nonzero(rhs.into())
+    }
+    /// `self / rhs`, for a dividend without a witness, such as
+    /// [`IntervalSign::ContainsZero`].
+    pub fn div_negative(self, rhs: Interval<Negative<T>>) -> Self {
+        self.div_nonzero(rhs.into())
+    }
+    /// `rhs` must not contain zero.
+    fn div_nonzero(self, rhs: Self) -> Self {
+        bounds([
+            self.lo.clone() / rhs.lo.clone(),
+            self.lo / rhs.hi.clone(),
+            self.hi.clone() / rhs.lo,
+            self.hi / rhs.hi,
+        ])
+    }
+}
+
+impl<T, U> ops::Neg for Interval<T>
+where
+    T: ops::Neg<Output = U>,
+{
+    type Output = Interval<U>;
+
+    fn neg(self) -> Self::Output {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+//////////////
+// Rounding //
+//////////////
+
+macro_rules! round_outward {
+    ($($f:ty),*) => {$(
+        impl Interval<$f> {
+            /// Widen by one unit in the last place on each side, so that the
+            /// interval contains any value that a rounded bound was computed
+            /// from.
+            pub fn round_outward(self) -> Self {
+                Self {
+                    lo: self.lo.next_down(),
+                    hi: self.hi.next_up(),
+                }
+            }
+        }
+
+        impl Interval<Positive<$f>> {
+            /// Widen by one unit in the last place on each side, except that the
+            /// lower bound never rounds to zero.
+            pub fn round_outward(self) -> Self {
+                let lo = self.lo.into_inner();
+                Self {
+                    lo: Positive::new(lo.next_down()).unwrap_or(Positive::new_unchecked(lo)),
+                    hi: self.hi.map_unchecked(<$f>::next_up),
+                }
+            }
+        }
+
+        impl Interval<Negative<$f>> {
+            /// Widen by one unit in the last place on each side, except that the
+            /// upper bound never rounds to zero.
+            pub fn round_outward(self) -> Self {
+                let hi = self.hi.into_inner();
+                Self {
+                    lo: self.lo.map_unchecked(<$f>::next_down),
+                    hi: Negative::new(hi.next_up()).unwrap_or(Negative::new_unchecked(hi)),
+                }
+            }
+        }
+    )*};
+}
+
+round_outward!(f32, f64);
@@ -8,6 +8,8 @@
 //! |           | `Negative` | `Positive`      | ?          | No          |
 //! |           | `Positive` | `impl Unsigned` | `Positive` | Yes         |
 //! |           | `Negative` | `impl Unsigned` | ?          | No          |
+//! |           | `Positive` | `impl Float`    | ?          | No          |
+//! |           | `Negative` | `impl Float`    | ?          | No          |
 //! | Sub       | `Positive` | `Positive`      | ?          | No          |
 //! |           | `Negative` | `Negative`      | ?          | No          |
 //! |           | `Positive` | `Negative`      | `Positive` | Yes         |
@@ -20,69 +22,248 @@
 //! |           | `Negative` | `Positive`      | `Negative` | Yes         |
 //! |           | `Positive` | `impl Unsigned` | ?          | No          |
 //! |           | `Negative` | `impl Unsigned` | ?          | No          |
+//! |           | `Positive` | `impl Float`    | ?          | No          |
+//! |           | `Negative` | `impl Float`    | ?          | No          |
 //! | Div       | `Positive` | `Positive`      | `Positive` | Yes         |
 //! |           | `Negative` | `Negative`      | `Positive` | No          |
 //! |           | `Positive` | `Negative`      | `Negative` | No          |
 //! |           | `Negative` | `Positive`      | `Negative` | Yes         |
 //! |           | `Positive` | `impl Unsigned` | `Positive` | Yes         |
-//! |           | `Negative` | `impl Unsigned` | `Negative` | Yes         |
+//! |           | `Negative` | `impl Unsigned` | ?          | No          |
 //! | Neg       | `Positive` |                 | `Negative` |             |
 //! |           | `Negative` |                 | `Positive` |             |
+//!
+//! `impl Float` is `f32` or `f64`.
+//! Signed integers have no such rows, because an unsuffixed literal in
+//! `Negative<i8> + 1` could then be either `u8` or `i8`.
+//!
+//! A primitive unsigned integer or float may also be on the left hand side of
+//! a commutative `impl Unsigned` or `impl Float` row, by value.
+//!
+//! | Operation | LHS             | RHS        | Output     |
+//! | --------- | --------------- | ---------- | ---------- |
+//! | Add       | `impl Unsigned` | `Positive` | `Positive` |
+//! |           | `impl Unsigned` | `Negative` | ?          |
+//! |           | `impl Float`    | `Positive` | ?          |
+//! |           | `impl Float`    | `Negative` | ?          |
+//! | Mul       | `impl Unsigned` | `Positive` | ?          |
+//! |           | `impl Unsigned` | `Negative` | ?          |
+//! |           | `impl Float`    | `Positive` | ?          |
+//! |           | `impl Float`    | `Negative` | ?          |
+//!
+//! Operations also accept borrowed operands (`&lhs + &rhs`, `&lhs + rhs` and
+//! `lhs + &rhs`), as do assignable operations (`lhs += &rhs`), so big numbers
+//! like `num::BigInt` needn't be cloned.
+//! `impl Unsigned` is the inner type if it's [`Unsigned`], the unsigned integer of the
+//! same width as a signed one (`Negative<i8> + 1_u8`), or any primitive unsigned
+//! integer for `num::BigInt` and `num::BigUint`.
+//! Only its built-in implementations may be borrowed, and only by the same type.
+//! Narrower unsigned integers aren't widened, so that unsuffixed literals still
+//! infer: write `Positive<u64> + u64::from(1_u8)`, not `Positive<u64> + 1_u8`.
+//! Like the primitive operators, a signed inner type with an `impl Unsigned`
+//! panics on overflow in debug builds, and wraps in release builds.
+//!
+//! Some rows which output a witness also have `checked_*` versions, which
+//! return `None` on overflow or if the result would break the witness, such as
+//! a product of decimals rounding to zero.
+//! They are [`num::CheckedAdd`] for either witness, [`num::CheckedMul`] and
+//! [`num::CheckedDiv`] for [`Positive`], and `checked_sub` of the opposite
+//! witness.
+//! The other rows which output a witness, such as `Negative * Negative` or
+//! `Positive + impl Unsigned`, have none.
+//!
+//! [`Positive`] and [`Negative`] are [`Witness`]es for the [`IsPositive`] and
+//! [`IsNegative`] [`Predicate`]s.
+//! Implement [`Predicate`] to define your own witnesses.
+//! Predicates compose with [`And`], [`Or`] and [`Not`], and witnesses can be
+//! weakened to any predicate they [`Implies`], such as with
+//! [`Witness::as_positive`].
+//!
+//! [`Even`] and [`Odd`] are witnesses of an integer's parity, which determines
+//! the sign of a power.
+//! Powers of integers are taken with [`num::traits::Pow`].
+//! Floats have none, because a power of a float may underflow to zero.
+//!
+//! | Operation | LHS        | RHS             | Output     | Assignable? |
+//! | --------- | ---------- | --------------- | ---------- | ----------- |
+//! | Add       | `Even`     | `Even`          | `Even`     | Yes         |
+//! |           | `Odd`      | `Odd`           | `Even`     | No          |
+//! |           | `Even`     | `Odd`           | `Odd`      | No          |
+//! |           | `Odd`      | `Even`          | `Odd`      | Yes         |
+//! | Sub       | `Even`     | `Even`          | `Even`     | Yes         |
+//! |           | `Odd`      | `Odd`           | `Even`     | No          |
+//! |           | `Even`     | `Odd`           | `Odd`      | No          |
+//! |           | `Odd`      | `Even`          | `Odd`      | Yes         |
+//! | Mul       | `Even`     | `Even`          | `Even`     | Yes         |
+//! |           | `Odd`      | `Odd`           | `Odd`      | Yes         |
+//! |           | `Even`     | `Odd`           | `Even`     | Yes         |
+//! |           | `Odd`      | `Even`          | `Even`     | No          |
+//! | Neg       | `Even`     |                 | `Even`     |             |
+//! |           | `Odd`      |                 | `Odd`      |             |
+//! | Pow       | `Positive` | `impl Unsigned` | `Positive` |             |
+//! |           | `Positive` | `Even`          | `Positive` |             |
+//! |           | `Positive` | `Odd`           | `Positive` |             |
+//! |           | `Negative` | `Even`          | `Positive` |             |
+//! |           | `Negative` | `Odd`           | `Negative` |             |
+//!
+//! [`PowerOfTwo`] is a witness for primitive integers, which implies
+//! [`Positive`], and divides unsigned integers with shifts and masks.
+//!
+//! [`NonZeroComplex`] is a witness that a `num::Complex` isn't zero, so its magnitude is
+//! [`Positive`].
+//!
+//! Witnesses of primitive integers convert with `From` when every value fits,
+//! such as `Positive<i8>` into `Positive<u8>`, and with `TryFrom` otherwise.
+//! `unsigned_abs` and `to_signed` move between signed and unsigned witnesses of
+//! the same width.
+//!
+//! Witnesses of `num::rational::Ratio` divide exactly, can be built from
+//! witnessed parts with `from_parts`, and have witnessed `numer` and `denom`.
+//!
+//! [`Probability`], [`PositiveProbability`] and [`OpenUnit`] are witnesses that a
+//! float is in `[0, 1]`, `(0, 1]` or `(0, 1)`.
+//! [`Probability`]s are closed under multiplication, but the product of
+//! [`PositiveProbability`]s or [`OpenUnit`]s may underflow to zero, so it's a
+//! [`Probability`].
+//!
+//! [`AtomicPositiveU32`], [`AtomicPositiveU64`] and [`AtomicPositiveUsize`]
+//! are counters which can be shared between threads.
+//!
+//! [`Interval`] is a closed interval, and intervals of witnesses, like
+//! `Interval<Positive<T>>`, keep their sign through arithmetic.
+//!
+//! [`InRange`] is a witness that a primitive integer lies within const-generic bounds.
+//!
+//! # Features
+//! - `arbitrary`: `arbitrary::Arbitrary` for primitive witnesses.
+//! - `bytemuck`: `bytemuck::NoUninit` and `bytemuck::CheckedBitPattern`, so
+//!   that `bytemuck::checked` casts into witnesses check the predicate.
+//! - `clap`: `clap::builder::ValueParserFactory` for primitive witnesses, so
+//!   they can be used as arguments.
+//! - `derive`: [`WitnessOps`](macro@WitnessOps), which forwards operators,
+//!   `Deref`, `Display` and serde from a witness field to a newtype.
+//! - `diesel`: `diesel::deserialize::FromSql`, `diesel::deserialize::Queryable`,
+//!   `diesel::serialize::ToSql` and `diesel::expression::AsExpression` for all
+//!   witnesses.
+//! - `fixed`: `num` traits for `fixed` numbers, so that they can be witnessed.
+//! - `proptest`: `proptest::arbitrary::Arbitrary` for primitive witnesses.
+//! - `quickcheck`: `quickcheck::Arbitrary` for primitive witnesses.
+//! - `rand`: `rand::distributions::Standard` for primitive witnesses, and
+//!   `rand::distributions::uniform::SampleUniform` for all witnesses.
+//! - `rusqlite`: `rusqlite::types::FromSql` and `rusqlite::types::ToSql` for
+//!   all witnesses.
+//! - `rust_decimal`: `round_dp` and `round_dp_with_strategy` for witnesses of
+//!   `rust_decimal::Decimal`, which fail if rounding breaks the witness.
+//! - `sqlx`: `sqlx::Type`, `sqlx::Encode` and `sqlx::Decode` for all witnesses.
+//! - `zerocopy`: `zerocopy::IntoBytes`, `zerocopy::Immutable` and
+//!   `zerocopy::KnownLayout`.
+//!   Casting _into_ a witness is done with `zerocopy::FromBytes` on `T`, and
+//!   then [`Positive::slice_from`] or [`Negative::slice_from`].
+//!   `zerocopy::TryFromBytes` isn't implemented, because it may only be
+//!   derived, and the derive can't check the predicate.
 
 use std::{fmt, ops};
 
+#[macro_use]
+mod table;
+
+mod atomic;
+mod complex;
+mod compose;
+mod convert;
+mod in_range;
+mod interval;
+mod parity;
+mod power_of_two;
+mod ratio;
+mod unit;
+mod unsigned;
+mod witness;
+
+pub use atomic::*;
+pub use complex::{IsNonZero, NonZeroComplex, NotNonZeroComplex};
+pub use compose::{And, Given, Here, Implies, Left, Not, Or, OrLeft, OrRight, Right};
+pub use in_range::{GreaterThan, InRange, IsInRange, LessThan, NotInRange, RangeInt};
+pub use interval::{Interval, IntervalSign};
+pub use parity::{Even, IsEven, IsOdd, NotEven, NotOdd, Odd};
+pub use power_of_two::{IsPowerOfTwo, NotPowerOfTwo, PowerOfTwo};
+pub use unit::{
+    IsOpenUnit, IsPositiveProbability, IsProbability, NotOpenUnit, NotPositiveProbability,
+    NotProbability, OpenUnit, PositiveProbability, Probability,
+};
+pub use witness::{Describe, Predicate, Rejected, Witness};
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "clap")]
+mod clap;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "clap")]
+pub use clap::{NegativeValueParser, PositiveValueParser};
+#[cfg(feature = "derive")]
+pub use numwit_derive::WitnessOps;
+#[cfg(feature = "rand")]
+pub use rand::{NegativeUniform, PositiveUniform};
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use num;
+    pub use serde;
+}
+
 /////////////////
 // Positive<T> //
 /////////////////
 
-/// A guarantee that `T > 0`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::AsRef)]
-#[repr(transparent)]
-pub struct Positive<T>(T);
+/// A [`Predicate`] that `T > 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsPositive;
 
-impl<T> Positive<T> {
-    pub fn into_inner(self) -> T {
-        self.0
-    }
-    pub fn inner(&self) -> &T {
-        self.as_ref()
-    }
-    pub fn new_unchecked(value: T) -> Self {
-        Self(value)
-    }
-    pub fn map_unchecked(self, mut f: impl FnMut(T) -> T) -> Self {
-        Self(f(self.0))
+impl Describe for IsPositive {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Positive")
     }
-    pub fn mut_unchecked(&mut self) -> &mut T {
-        &mut self.0
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("positive")
     }
 }
 
-impl<T> Positive<T>
+impl<T> Predicate<T> for IsPositive
 where
     T: num::Zero + PartialOrd,
 {
-    pub fn new(value: T) -> Result<Self, NotPositive<T>> {
-        match value > T::zero() {
-            true => Ok(Self(value)),
-            false => Err(NotPositive(value)),
-        }
-    }
-    pub fn map(self, mut f: impl FnMut(T) -> T) -> Result<Self, NotPositive<T>> {
-        Self::new(f(self.0))
-    }
-    /// # Panics
-    /// - If `T <= 0`
-    pub fn assert(&self) {
-        assert!(self.0 <= T::zero(), "non-positive number");
-    }
-    /// # Panics
-    /// - If `T <= 0`
-    pub fn debug_assert(&self) {
-        debug_assert!(self.0 <= T::zero(), "non-positive number");
+    fn test(value: &T) -> bool {
+        *value > T::zero()
     }
 }
 
+/// A guarantee that `T > 0`.
+pub type Positive<T> = Witness<T, IsPositive>;
+
+/// A value which wasn't positive, in `.0`.
+///
+/// This used to be a tuple struct of just the value, so it can no longer be
+/// built or matched as `NotPositive(value)`, and it debugs as `Rejected(value)`.
+/// Build it with [`Rejected::new`] instead.
+pub type NotPositive<T> = Rejected<T, IsPositive>;
+
 impl<T> num::One for Positive<T>
 where
     T: num::One,
@@ -92,104 +273,42 @@ where
     }
 }
 
-#[derive(Debug)]
-pub struct NotPositive<T>(pub T);
-
-impl<T: fmt::Display> fmt::Display for NotPositive<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("The value {} was not positive", self.0))
-    }
-}
-
-impl<T: fmt::Display + fmt::Debug> std::error::Error for NotPositive<T> {}
-
-impl<T> PartialEq<T> for Positive<T>
-where
-    T: PartialEq<T>,
-{
-    fn eq(&self, other: &T) -> bool {
-        self.0.eq(other)
-    }
-}
-
-impl<'de, T> serde::Deserialize<'de> for Positive<T>
-where
-    T: serde::Deserialize<'de> + num::Zero + PartialOrd + fmt::Display,
-{
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let t = T::deserialize(deserializer)?;
-        Self::new(t).map_err(serde::de::Error::custom)
-    }
-}
-
-impl<T> serde::Serialize for Positive<T>
-where
-    T: serde::Serialize,
-{
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        self.as_ref().serialize(serializer)
-    }
-}
-
 /////////////////
 // Negative<T> //
 /////////////////
 
-/// A guarantee that `T < 0`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::AsRef)]
-#[repr(transparent)]
-pub struct Negative<T>(T);
+/// A [`Predicate`] that `T < 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsNegative;
 
-impl<T> Negative<T> {
-    pub fn into_inner(self) -> T {
-        self.0
-    }
-    pub fn inner(&self) -> &T {
-        self.as_ref()
-    }
-    pub fn new_unchecked(value: T) -> Self {
-        Self(value)
-    }
-    pub fn map_unchecked(self, mut f: impl FnMut(T) -> T) -> Self {
-        Self(f(self.0))
+impl Describe for IsNegative {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Negative")
     }
-    pub fn mut_unchecked(&mut self) -> &mut T {
-        &mut self.0
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("negative")
     }
 }
 
-impl<T> Negative<T>
+impl<T> Predicate<T> for IsNegative
 where
     T: num::Zero + PartialOrd,
 {
-    pub fn new(value: T) -> Result<Self, NotNegative<T>> {
-        match value < T::zero() {
-            true => Ok(Self(value)),
-            false => Err(NotNegative(value)),
-        }
-    }
-    pub fn map(self, mut f: impl FnMut(T) -> T) -> Result<Self, NotNegative<T>> {
-        Self::new(f(self.0))
-    }
-
-    /// # Panics
-    /// - If `T >= 0`
-    pub fn assert(&self) {
-        assert!(self.0 >= T::zero(), "non-negative number");
-    }
-    /// # Panics
-    /// - If `T >= 0`
-    pub fn debug_assert(&self) {
-        debug_assert!(self.0 >= T::zero(), "non-negative number");
+    fn test(value: &T) -> bool {
+        *value < T::zero()
     }
 }
 
+/// A guarantee that `T < 0`.
+pub type Negative<T> = Witness<T, IsNegative>;
+
+/// A value which wasn't negative, in `.0`.
+///
+/// This used to be a tuple struct of just the value, so it can no longer be
+/// built or matched as `NotNegative(value)`, and it debugs as `Rejected(value)`.
+/// Build it with [`Rejected::new`] instead.
+pub type NotNegative<T> = Rejected<T, IsNegative>;
+
 impl<T> Negative<T>
 where
     T: num::One + ops::Neg<Output = T>,
@@ -199,529 +318,301 @@ where
     }
 }
 
-impl<'de, T> serde::Deserialize<'de> for Negative<T>
-where
-    T: serde::Deserialize<'de> + num::Zero + PartialOrd + fmt::Display,
-{
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let t = T::deserialize(deserializer)?;
-        Self::new(t).map_err(serde::de::Error::custom)
-    }
-}
-
-impl<T> serde::Serialize for Negative<T>
-where
-    T: serde::Serialize,
-{
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        self.as_ref().serialize(serializer)
-    }
-}
-
-#[derive(Debug)]
-pub struct NotNegative<T>(pub T);
-
-impl<T: fmt::Display> fmt::Display for NotNegative<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("The value {} was not negative", self.0))
-    }
-}
+//////////////
+// Unsigned //
+//////////////
 
-impl<T: fmt::Display + fmt::Debug> std::error::Error for NotNegative<T> {}
-
-impl<T> PartialEq<T> for Negative<T>
-where
-    T: PartialEq<T>,
-{
-    fn eq(&self, other: &T) -> bool {
-        self.0.eq(other)
-    }
-}
+/// An unsigned number, which may be the right hand side of the
+/// `impl Unsigned` rows.
+///
+/// It may be the inner type itself, or the same width as a signed inner type,
+/// so `Negative<i8> + 1_u8` is `-1 + 1`.
+/// Narrower integers aren't widened, so that an unsuffixed literal in
+/// `Positive<u64> + 1` has a single type to infer; widen them yourself, as in
+/// `Positive<u64> + u64::from(1_u8)`.
+///
+/// Those rows used to take any [`num::Unsigned`] type.
+/// Implement this for your own [`num::Unsigned`] types to keep using them in
+/// those rows by value.
+/// Only the types implemented here may also be borrowed.
+pub trait Unsigned: num::Unsigned {}
+
+macro_rules! unsigned {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Unsigned for $ty {}
+    )*};
+}
+
+unsigned!(u8, u16, u32, u64, u128, usize, num::BigUint);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Add       | `Positive` | `Positive`      | `Positive` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Add<Positive<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::Add<RhsT, Output = OutT>,
-{
-    type Output = Positive<OutT>;
-
-    fn add(self, rhs: Positive<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 + rhs.0)
-    }
-}
-
-impl<LhsT, RhsT> ops::AddAssign<Positive<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::AddAssign<RhsT>,
-{
-    fn add_assign(&mut self, rhs: Positive<RhsT>) {
-        self.mut_unchecked().add_assign(rhs.0)
-    }
-}
+binop!(Add::add, Positive, Positive => Positive);
+assign!(AddAssign::add_assign, Positive, Positive);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Add       | `Negative` | `Negative`      | `Negative` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Add<Negative<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::Add<RhsT, Output = OutT>,
-{
-    type Output = Negative<OutT>;
-
-    fn add(self, rhs: Negative<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 + rhs.0)
-    }
-}
-
-impl<LhsT, RhsT> ops::AddAssign<Negative<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::AddAssign<RhsT>,
-{
-    fn add_assign(&mut self, rhs: Negative<RhsT>) {
-        self.mut_unchecked().add_assign(rhs.0)
-    }
-}
+binop!(Add::add, Negative, Negative => Negative);
+assign!(AddAssign::add_assign, Negative, Negative);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Add       | `Positive` | `Negative`      | ?          | No          |
-impl<LhsT, RhsT, OutT> ops::Add<Negative<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::Add<RhsT, Output = OutT>,
-{
-    type Output = OutT;
-
-    fn add(self, rhs: Negative<RhsT>) -> Self::Output {
-        self.0 + rhs.0
-    }
-}
+binop!(Add::add, Positive, Negative => ?);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Add       | `Negative` | `Positive`      | ?          | No          |
-impl<LhsT, RhsT, OutT> ops::Add<Positive<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::Add<RhsT, Output = OutT>,
-{
-    type Output = OutT;
-
-    fn add(self, rhs: Positive<RhsT>) -> Self::Output {
-        self.0 + rhs.0
-    }
-}
+binop!(Add::add, Negative, Positive => ?);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Add       | `Positive` | `impl Unsigned` | `Positive` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Add<RhsT> for Positive<LhsT>
-where
-    LhsT: ops::Add<RhsT, Output = OutT>,
-    RhsT: num::Unsigned,
-{
-    type Output = Positive<OutT>;
-
-    fn add(self, rhs: RhsT) -> Self::Output {
-        Self::Output::new_unchecked(self.0 + rhs)
-    }
-}
-
-impl<LhsT, RhsT> ops::AddAssign<RhsT> for Positive<LhsT>
-where
-    LhsT: ops::AddAssign<RhsT>,
-    RhsT: num::Unsigned,
-{
-    fn add_assign(&mut self, rhs: RhsT) {
-        self.mut_unchecked().add_assign(rhs)
-    }
-}
+binop!(Add::add, Positive, impl Unsigned => Positive);
+assign!(AddAssign::add_assign, Positive, impl Unsigned);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Add       | `Negative` | `impl Unsigned` | ?          | No          |
-impl<LhsT, RhsT, OutT> ops::Add<RhsT> for Negative<LhsT>
-where
-    LhsT: ops::Add<RhsT, Output = OutT>,
-    RhsT: num::Unsigned,
-{
-    type Output = OutT;
+binop!(Add::add, Negative, impl Unsigned => ?);
 
-    fn add(self, rhs: RhsT) -> Self::Output {
-        self.0 + rhs
-    }
-}
+// | Operation | LHS        | RHS             | Output     | Assignable? |
+// | --------- | ---------- | --------------- | ---------- | ----------- |
+// | Add       | `Positive` | `impl Float`    | ?          | No          |
+binop!(Add::add, Positive, impl Float => ?);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
-// | Sub       | `Positive` | `Positive`      | ?          | No          |
-impl<LhsT, RhsT, OutT> ops::Sub<Positive<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::Sub<RhsT, Output = OutT>,
-{
-    type Output = OutT;
+// | Add       | `Negative` | `impl Float`    | ?          | No          |
+binop!(Add::add, Negative, impl Float => ?);
 
-    fn sub(self, rhs: Positive<RhsT>) -> Self::Output {
-        self.0 - rhs.0
-    }
-}
+// | Operation | LHS        | RHS             | Output     | Assignable? |
+// | --------- | ---------- | --------------- | ---------- | ----------- |
+// | Sub       | `Positive` | `Positive`      | ?          | No          |
+binop!(Sub::sub, Positive, Positive => ?);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Sub       | `Negative` | `Negative`      | ?          | No          |
-impl<LhsT, RhsT, OutT> ops::Sub<Negative<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::Sub<RhsT, Output = OutT>,
-{
-    type Output = OutT;
-
-    fn sub(self, rhs: Negative<RhsT>) -> Self::Output {
-        self.0 - rhs.0
-    }
-}
+binop!(Sub::sub, Negative, Negative => ?);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Sub       | `Positive` | `Negative`      | `Positive` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Sub<Negative<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::Sub<RhsT, Output = OutT>,
-{
-    type Output = Positive<OutT>;
-
-    fn sub(self, rhs: Negative<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 - rhs.0)
-    }
-}
-
-impl<LhsT, RhsT> ops::SubAssign<Negative<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::SubAssign<RhsT>,
-{
-    fn sub_assign(&mut self, rhs: Negative<RhsT>) {
-        self.mut_unchecked().sub_assign(rhs.0)
-    }
-}
+binop!(Sub::sub, Positive, Negative => Positive);
+assign!(SubAssign::sub_assign, Positive, Negative);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Sub       | `Negative` | `Positive`      | `Negative` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Sub<Positive<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::Sub<RhsT, Output = OutT>,
-{
-    type Output = Negative<OutT>;
-
-    fn sub(self, rhs: Positive<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 - rhs.0)
-    }
-}
-
-impl<LhsT, RhsT> ops::SubAssign<Positive<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::SubAssign<RhsT>,
-{
-    fn sub_assign(&mut self, rhs: Positive<RhsT>) {
-        self.mut_unchecked().sub_assign(rhs.0)
-    }
-}
+binop!(Sub::sub, Negative, Positive => Negative);
+assign!(SubAssign::sub_assign, Negative, Positive);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Sub       | `Positive` | `impl Unsigned` | ?          | No          |
-impl<LhsT, RhsT, OutT> ops::Sub<RhsT> for Positive<LhsT>
-where
-    LhsT: ops::Sub<RhsT, Output = OutT>,
-    RhsT: num::Unsigned,
-{
-    type Output = OutT;
-
-    fn sub(self, rhs: RhsT) -> Self::Output {
-        self.0 - rhs
-    }
-}
+binop!(Sub::sub, Positive, impl Unsigned => ?);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Sub       | `Negative` | `impl Unsigned` | `Negative` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Sub<RhsT> for Negative<LhsT>
-where
-    LhsT: ops::Sub<RhsT, Output = OutT>,
-    RhsT: num::Unsigned,
-{
-    type Output = Negative<OutT>;
-
-    fn sub(self, rhs: RhsT) -> Self::Output {
-        Self::Output::new_unchecked(self.0 - rhs)
-    }
-}
-
-impl<LhsT, RhsT> ops::SubAssign<RhsT> for Negative<LhsT>
-where
-    LhsT: ops::SubAssign<RhsT>,
-    RhsT: num::Unsigned,
-{
-    fn sub_assign(&mut self, rhs: RhsT) {
-        self.mut_unchecked().sub_assign(rhs)
-    }
-}
+binop!(Sub::sub, Negative, impl Unsigned => Negative);
+assign!(SubAssign::sub_assign, Negative, impl Unsigned);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Mul       | `Positive` | `Positive`      | `Positive` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Mul<Positive<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::Mul<RhsT, Output = OutT>,
-{
-    type Output = Positive<OutT>;
-
-    fn mul(self, rhs: Positive<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 * rhs.0)
-    }
-}
-
-impl<LhsT, RhsT> ops::MulAssign<Positive<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::MulAssign<RhsT>,
-{
-    fn mul_assign(&mut self, rhs: Positive<RhsT>) {
-        self.mut_unchecked().mul_assign(rhs.0)
-    }
-}
+binop!(Mul::mul, Positive, Positive => Positive);
+assign!(MulAssign::mul_assign, Positive, Positive);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Mul       | `Negative` | `Negative`      | `Positive` | No          |
-impl<LhsT, RhsT, OutT> ops::Mul<Negative<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::Mul<RhsT, Output = OutT>,
-{
-    type Output = Positive<OutT>;
-
-    fn mul(self, rhs: Negative<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 * rhs.0)
-    }
-}
+binop!(Mul::mul, Negative, Negative => Positive);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Mul       | `Positive` | `Negative`      | `Negative` | No          |
-impl<LhsT, RhsT, OutT> ops::Mul<Negative<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::Mul<RhsT, Output = OutT>,
-{
-    type Output = Negative<OutT>;
-
-    fn mul(self, rhs: Negative<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 * rhs.0)
-    }
-}
+binop!(Mul::mul, Positive, Negative => Negative);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Mul       | `Negative` | `Positive`      | `Negative` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Mul<Positive<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::Mul<RhsT, Output = OutT>,
-{
-    type Output = Negative<OutT>;
-
-    fn mul(self, rhs: Positive<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 * rhs.0)
-    }
-}
-
-impl<LhsT, RhsT> ops::MulAssign<Positive<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::MulAssign<RhsT>,
-{
-    fn mul_assign(&mut self, rhs: Positive<RhsT>) {
-        self.mut_unchecked().mul_assign(rhs.0)
-    }
-}
+binop!(Mul::mul, Negative, Positive => Negative);
+assign!(MulAssign::mul_assign, Negative, Positive);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Mul       | `Positive` | `impl Unsigned` | ?          | No          |
-impl<LhsT, RhsT, OutT> ops::Mul<RhsT> for Positive<LhsT>
-where
-    LhsT: ops::Mul<RhsT, Output = OutT>,
-    RhsT: num::Unsigned,
-{
-    type Output = OutT;
-
-    fn mul(self, rhs: RhsT) -> Self::Output {
-        self.0 * rhs
-    }
-}
+binop!(Mul::mul, Positive, impl Unsigned => ?);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Mul       | `Negative` | `impl Unsigned` | ?          | No          |
-impl<LhsT, RhsT, OutT> ops::Mul<RhsT> for Negative<LhsT>
-where
-    LhsT: ops::Mul<RhsT, Output = OutT>,
-    RhsT: num::Unsigned,
-{
-    type Output = OutT;
-
-    fn mul(self, rhs: RhsT) -> Self::Output {
-        self.0 * rhs
-    }
-}
+binop!(Mul::mul, Negative, impl Unsigned => ?);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
-// | Div       | `Positive` | `Positive`      | `Positive` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Div<Positive<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::Div<RhsT, Output = OutT>,
-{
-    type Output = Positive<OutT>;
+// | Mul       | `Positive` | `impl Float`    | ?          | No          |
+binop!(Mul::mul, Positive, impl Float => ?);
 
-    fn div(self, rhs: Positive<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 / rhs.0)
-    }
-}
+// | Operation | LHS        | RHS             | Output     | Assignable? |
+// | --------- | ---------- | --------------- | ---------- | ----------- |
+// | Mul       | `Negative` | `impl Float`    | ?          | No          |
+binop!(Mul::mul, Negative, impl Float => ?);
 
-impl<LhsT, RhsT> ops::DivAssign<Positive<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::DivAssign<RhsT>,
-{
-    fn div_assign(&mut self, rhs: Positive<RhsT>) {
-        self.mut_unchecked().div_assign(rhs.0)
-    }
-}
+// | Operation | LHS        | RHS             | Output     | Assignable? |
+// | --------- | ---------- | --------------- | ---------- | ----------- |
+// | Div       | `Positive` | `Positive`      | `Positive` | Yes         |
+binop!(Div::div, Positive, Positive => Positive);
+assign!(DivAssign::div_assign, Positive, Positive);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Div       | `Negative` | `Negative`      | `Positive` | No          |
-impl<LhsT, RhsT, OutT> ops::Div<Negative<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::Div<RhsT, Output = OutT>,
-{
-    type Output = Positive<OutT>;
-
-    fn div(self, rhs: Negative<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 / rhs.0)
-    }
-}
+binop!(Div::div, Negative, Negative => Positive);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Div       | `Positive` | `Negative`      | `Negative` | No          |
-impl<LhsT, RhsT, OutT> ops::Div<Negative<RhsT>> for Positive<LhsT>
-where
-    LhsT: ops::Div<RhsT, Output = OutT>,
-{
-    type Output = Negative<OutT>;
-
-    fn div(self, rhs: Negative<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 / rhs.0)
-    }
-}
+binop!(Div::div, Positive, Negative => Negative);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Div       | `Negative` | `Positive`      | `Negative` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Div<Positive<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::Div<RhsT, Output = OutT>,
-{
-    type Output = Negative<OutT>;
-
-    fn div(self, rhs: Positive<RhsT>) -> Self::Output {
-        Self::Output::new_unchecked(self.0 / rhs.0)
-    }
-}
-
-impl<LhsT, RhsT> ops::DivAssign<Positive<RhsT>> for Negative<LhsT>
-where
-    LhsT: ops::DivAssign<RhsT>,
-{
-    fn div_assign(&mut self, rhs: Positive<RhsT>) {
-        self.mut_unchecked().div_assign(rhs.0)
-    }
-}
+binop!(Div::div, Negative, Positive => Negative);
+assign!(DivAssign::div_assign, Negative, Positive);
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Div       | `Positive` | `impl Unsigned` | `Positive` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Div<RhsT> for Positive<LhsT>
+binop!(Div::div, Positive, impl Unsigned => Positive);
+assign!(DivAssign::div_assign, Positive, impl Unsigned);
+
+// | Operation | LHS        | RHS             | Output     | Assignable? |
+// | --------- | ---------- | --------------- | ---------- | ----------- |
+// | Div       | `Negative` | `impl Unsigned` | ?          | No          |
+binop!(Div::div, Negative, impl Unsigned => ?);
+
+///////////////////////////////
+// Primitive left hand sides //
+///////////////////////////////
+
+// | Operation | LHS             | RHS        | Output     | Assignable? |
+// | --------- | --------------- | ---------- | ---------- | ----------- |
+// | Add       | `impl Unsigned` | `Positive` | `Positive` | No          |
+binop!(Add::add, impl Unsigned, Positive => Positive);
+
+// | Operation | LHS             | RHS        | Output     | Assignable? |
+// | --------- | --------------- | ---------- | ---------- | ----------- |
+// | Add       | `impl Unsigned` | `Negative` | ?          | No          |
+binop!(Add::add, impl Unsigned, Negative => ?);
+
+// | Operation | LHS             | RHS        | Output     | Assignable? |
+// | --------- | --------------- | ---------- | ---------- | ----------- |
+// | Add       | `impl Float`    | `Positive` | ?          | No          |
+binop!(Add::add, impl Float, Positive => ?);
+
+// | Operation | LHS             | RHS        | Output     | Assignable? |
+// | --------- | --------------- | ---------- | ---------- | ----------- |
+// | Add       | `impl Float`    | `Negative` | ?          | No          |
+binop!(Add::add, impl Float, Negative => ?);
+
+// | Operation | LHS             | RHS        | Output     | Assignable? |
+// | --------- | --------------- | ---------- | ---------- | ----------- |
+// | Mul       | `impl Unsigned` | `Positive` | ?          | No          |
+binop!(Mul::mul, impl Unsigned, Positive => ?);
+
+// | Operation | LHS             | RHS        | Output     | Assignable? |
+// | --------- | --------------- | ---------- | ---------- | ----------- |
+// | Mul       | `impl Unsigned` | `Negative` | ?          | No          |
+binop!(Mul::mul, impl Unsigned, Negative => ?);
+
+// | Operation | LHS             | RHS        | Output     | Assignable? |
+// | --------- | --------------- | ---------- | ---------- | ----------- |
+// | Mul       | `impl Float`    | `Positive` | ?          | No          |
+binop!(Mul::mul, impl Float, Positive => ?);
+
+// | Operation | LHS             | RHS        | Output     | Assignable? |
+// | --------- | --------------- | ---------- | ---------- | ----------- |
+// | Mul       | `impl Float`    | `Negative` | ?          | No          |
+binop!(Mul::mul, impl Float, Negative => ?);
+
+//////////////
+// Negation //
+//////////////
+
+neg!(Positive => Negative);
+neg!(Negative => Positive);
+
+/////////////
+// Checked //
+/////////////
+
+// These return [`None`] on overflow, and if the result would break the
+// witness, such as when a product rounds to zero.
+//
+// | Operation  | LHS        | RHS        | Output     |
+// | ---------- | ---------- | ---------- | ---------- |
+// | CheckedAdd | `Positive` | `Positive` | `Positive` |
+// |            | `Negative` | `Negative` | `Negative` |
+// | CheckedSub | `Positive` | `Negative` | `Positive` |
+// |            | `Negative` | `Positive` | `Negative` |
+// | CheckedMul | `Positive` | `Positive` | `Positive` |
+// | CheckedDiv | `Positive` | `Positive` | `Positive` |
+
+impl<T> num::CheckedAdd for Positive<T>
 where
-    LhsT: ops::Div<RhsT, Output = OutT>,
-    RhsT: num::Unsigned,
+    T: num::CheckedAdd + num::Zero + PartialOrd,
 {
-    type Output = Positive<OutT>;
-
-    fn div(self, rhs: RhsT) -> Self::Output {
-        Self::Output::new_unchecked(self.0 / rhs)
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Self::new(self.0.checked_add(&v.0)?).ok()
     }
 }
 
-impl<LhsT, RhsT> ops::DivAssign<RhsT> for Positive<LhsT>
+impl<T> num::CheckedAdd for Negative<T>
 where
-    LhsT: ops::DivAssign<RhsT>,
-    RhsT: num::Unsigned,
+    T: num::CheckedAdd + num::Zero + PartialOrd,
 {
-    fn div_assign(&mut self, rhs: RhsT) {
-        self.mut_unchecked().div_assign(rhs)
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Self::new(self.0.checked_add(&v.0)?).ok()
     }
 }
 
-// | Operation | LHS        | RHS             | Output     | Assignable? |
-// | --------- | ---------- | --------------- | ---------- | ----------- |
-// | Div       | `Negative` | `impl Unsigned` | `Negative` | Yes         |
-impl<LhsT, RhsT, OutT> ops::Div<RhsT> for Negative<LhsT>
+impl<T> Positive<T>
 where
-    LhsT: ops::Div<RhsT, Output = OutT>,
-    RhsT: num::Unsigned,
+    T: num::CheckedSub + num::Zero + PartialOrd,
 {
-    type Output = Negative<OutT>;
-
-    fn div(self, rhs: RhsT) -> Self::Output {
-        Self::Output::new_unchecked(self.0 / rhs)
+    pub fn checked_sub(&self, v: &Negative<T>) -> Option<Self> {
+        Self::new(self.0.checked_sub(&v.0)?).ok()
     }
 }
 
-impl<LhsT, RhsT> ops::DivAssign<RhsT> for Negative<LhsT>
+impl<T> Negative<T>
 where
-    LhsT: ops::DivAssign<RhsT>,
-    RhsT: num::Unsigned,
+    T: num::CheckedSub + num::Zero + PartialOrd,
 {
-    fn div_assign(&mut self, rhs: RhsT) {
-        self.mut_unchecked().div_assign(rhs)
+    pub fn checked_sub(&self, v: &Positive<T>) -> Option<Self> {
+        Self::new(self.0.checked_sub(&v.0)?).ok()
     }
 }
 
-//////////////
-// Negation //
-//////////////
-
-impl<T, U> ops::Neg for Positive<T>
+impl<T> num::CheckedMul for Positive<T>
 where
-    T: ops::Neg<Output = U>,
+    T: num::CheckedMul + num::Zero + PartialOrd,
 {
-    type Output = Negative<U>;
-
-    fn neg(self) -> Self::Output {
-        Self::Output::new_unchecked(-self.0)
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Self::new(self.0.checked_mul(&v.0)?).ok()
     }
 }
 
-impl<T, U> ops::Neg for Negative<T>
+impl<T> num::CheckedDiv for Positive<T>
 where
-    T: ops::Neg<Output = U>,
+    T: num::CheckedDiv + num::Zero + PartialOrd,
 {
-    type Output = Positive<U>;
-
-    fn neg(self) -> Self::Output {
-        Self::Output::new_unchecked(-self.0)
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        Self::new(self.0.checked_div(&v.0)?).ok()
     }
 }
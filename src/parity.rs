@@ -0,0 +1,192 @@
+use std::fmt;
+
+use num::traits::Pow;
+
+use crate::{Describe, Negative, Positive, Predicate, Rejected, Unsigned, Witness};
+
+/// A [`Predicate`] that an integer is a multiple of two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsEven;
+
+/// A [`Predicate`] that an integer isn't a multiple of two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsOdd;
+
+impl Describe for IsEven {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Even")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("even")
+    }
+}
+
+impl<T: num::Integer> Predicate<T> for IsEven {
+    fn test(value: &T) -> bool {
+        value.is_even()
+    }
+}
+
+impl Describe for IsOdd {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Odd")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("odd")
+    }
+}
+
+impl<T: num::Integer> Predicate<T> for IsOdd {
+    fn test(value: &T) -> bool {
+        value.is_odd()
+    }
+}
+
+/// A guarantee that `T` is even.
+pub type Even<T> = Witness<T, IsEven>;
+
+pub type NotEven<T> = Rejected<T, IsEven>;
+
+/// A guarantee that `T` is odd.
+pub type Odd<T> = Witness<T, IsOdd>;
+
+pub type NotOdd<T> = Rejected<T, IsOdd>;
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Add       | `Even` | `Even` | `Even` | Yes         |
+binop!(Add::add, Even, Even => Even);
+assign!(AddAssign::add_assign, Even, Even);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Add       | `Odd`  | `Odd`  | `Even` | No          |
+binop!(Add::add, Odd, Odd => Even);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Add       | `Even` | `Odd`  | `Odd`  | No          |
+binop!(Add::add, Even, Odd => Odd);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Add       | `Odd`  | `Even` | `Odd`  | Yes         |
+binop!(Add::add, Odd, Even => Odd);
+assign!(AddAssign::add_assign, Odd, Even);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Sub       | `Even` | `Even` | `Even` | Yes         |
+binop!(Sub::sub, Even, Even => Even);
+assign!(SubAssign::sub_assign, Even, Even);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Sub       | `Odd`  | `Odd`  | `Even` | No          |
+binop!(Sub::sub, Odd, Odd => Even);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Sub       | `Even` | `Odd`  | `Odd`  | No          |
+binop!(Sub::sub, Even, Odd => Odd);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Sub       | `Odd`  | `Even` | `Odd`  | Yes         |
+binop!(Sub::sub, Odd, Even => Odd);
+assign!(SubAssign::sub_assign, Odd, Even);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Mul       | `Even` | `Even` | `Even` | Yes         |
+binop!(Mul::mul, Even, Even => Even);
+assign!(MulAssign::mul_assign, Even, Even);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Mul       | `Odd`  | `Odd`  | `Odd`  | Yes         |
+binop!(Mul::mul, Odd, Odd => Odd);
+assign!(MulAssign::mul_assign, Odd, Odd);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Mul       | `Even` | `Odd`  | `Even` | Yes         |
+binop!(Mul::mul, Even, Odd => Even);
+assign!(MulAssign::mul_assign, Even, Odd);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Mul       | `Odd`  | `Even` | `Even` | No          |
+binop!(Mul::mul, Odd, Even => Even);
+
+// | Operation | LHS    | RHS    | Output | Assignable? |
+// | --------- | ------ | ------ | ------ | ----------- |
+// | Neg       | `Even` |        | `Even` |             |
+// |           | `Odd`  |        | `Odd`  |             |
+neg!(Even => Even);
+neg!(Odd => Odd);
+
+// | Operation | LHS        | RHS             | Output     |
+// | --------- | ---------- | --------------- | ---------- |
+// | Pow       | `Positive` | `impl Unsigned` | `Positive` |
+// |           | `Positive` | `Even`          | `Positive` |
+// |           | `Positive` | `Odd`           | `Positive` |
+// |           | `Negative` | `Even`          | `Positive` |
+// |           | `Negative` | `Odd`           | `Negative` |
+//
+// Only for integers, because a power of a float may underflow to zero.
+impl<LhsT, RhsT, OutT> Pow<RhsT> for Positive<LhsT>
+where
+    LhsT: num::Integer + Pow<RhsT, Output = OutT>,
+    RhsT: Unsigned,
+{
+    type Output = Positive<OutT>;
+
+    fn pow(self, rhs: RhsT) -> Self::Output {
+        Self::Output::new_unchecked(self.0.pow(rhs))
+    }
+}
+
+impl<LhsT, RhsT, OutT> Pow<Even<RhsT>> for Positive<LhsT>
+where
+    LhsT: num::Integer + Pow<RhsT, Output = OutT>,
+{
+    type Output = Positive<OutT>;
+
+    fn pow(self, rhs: Even<RhsT>) -> Self::Output {
+        Self::Output::new_unchecked(self.0.pow(rhs.0))
+    }
+}
+
+impl<LhsT, RhsT, OutT> Pow<Odd<RhsT>> for Positive<LhsT>
+where
+    LhsT: num::Integer + Pow<RhsT, Output = OutT>,
+{
+    type Output = Positive<OutT>;
+
+    fn pow(self, rhs: Odd<RhsT>) -> Self::Output {
+        Self::Output::new_unchecked(self.0.pow(rhs.0))
+    }
+}
+
+impl<LhsT, RhsT, OutT> Pow<Even<RhsT>> for Negative<LhsT>
+where
+    LhsT: num::Integer + Pow<RhsT, Output = OutT>,
+{
+    type Output = Positive<OutT>;
+
+    fn pow(self, rhs: Even<RhsT>) -> Self::Output {
+        Self::Output::new_unchecked(self.0.pow(rhs.0))
+    }
+}
+
+impl<LhsT, RhsT, OutT> Pow<Odd<RhsT>> for Negative<LhsT>
+where
+    LhsT: num::Integer + Pow<RhsT, Output = OutT>,
+{
+    type Output = Negative<OutT>;
+
+    fn pow(self, rhs: Odd<RhsT>) -> Self::Output {
+        Self::Output::new_unchecked(self.0.pow(rhs.0))
+    }
+}
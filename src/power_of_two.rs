@@ -0,0 +1,116 @@
+use std::{fmt, ops};
+
+use crate::{Describe, Given, Implies, IsPositive, Positive, Predicate, Rejected, Witness};
+
+/// A [`Predicate`] that a primitive integer is a power of two.
+///
+/// This [`Implies`] [`IsPositive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsPowerOfTwo;
+
+impl Describe for IsPowerOfTwo {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PowerOfTwo")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a power of two")
+    }
+}
+
+impl<T: num::PrimInt> Predicate<T> for IsPowerOfTwo {
+    fn test(value: &T) -> bool {
+        *value > T::zero() && value.count_ones() == 1
+    }
+}
+
+impl Implies<IsPositive, Given> for IsPowerOfTwo {}
+
+/// A guarantee that `T` is a power of two.
+pub type PowerOfTwo<T> = Witness<T, IsPowerOfTwo>;
+
+pub type NotPowerOfTwo<T> = Rejected<T, IsPowerOfTwo>;
+
+impl<T: num::PrimInt> PowerOfTwo<T> {
+    /// The exponent `n`, where `self == 2^n`.
+    pub fn log2(&self) -> u32 {
+        self.0.trailing_zeros()
+    }
+    /// `self - 1`, which has every bit below `self` set.
+    pub fn mask(&self) -> T {
+        self.0 - T::one()
+    }
+    /// Round `value` down to a multiple of `self`.
+    pub fn align_down(&self, value: T) -> T {
+        value & !self.mask()
+    }
+    /// Round `value` up to a multiple of `self`.
+    ///
+    /// Returns [`None`] if the result would overflow.
+    pub fn align_up(&self, value: T) -> Option<T> {
+        Some(self.align_down(value.checked_add(&self.mask())?))
+    }
+    pub fn is_aligned(&self, value: T) -> bool {
+        value & self.mask() == T::zero()
+    }
+}
+
+impl<T> From<PowerOfTwo<T>> for Positive<T> {
+    fn from(value: PowerOfTwo<T>) -> Self {
+        value.weaken()
+    }
+}
+
+macro_rules! power_of_two {
+    ($($ty:ty),* $(,)?) => {$(
+        impl ops::Div<PowerOfTwo<$ty>> for $ty {
+            type Output = $ty;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: PowerOfTwo<$ty>) -> Self::Output {
+                self >> rhs.log2()
+            }
+        }
+
+        impl ops::DivAssign<PowerOfTwo<$ty>> for $ty {
+            #[allow(clippy::suspicious_op_assign_impl)]
+            fn div_assign(&mut self, rhs: PowerOfTwo<$ty>) {
+                *self >>= rhs.log2()
+            }
+        }
+
+        impl ops::Rem<PowerOfTwo<$ty>> for $ty {
+            type Output = $ty;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn rem(self, rhs: PowerOfTwo<$ty>) -> Self::Output {
+                self & rhs.mask()
+            }
+        }
+
+        impl ops::RemAssign<PowerOfTwo<$ty>> for $ty {
+            #[allow(clippy::suspicious_op_assign_impl)]
+            fn rem_assign(&mut self, rhs: PowerOfTwo<$ty>) {
+                *self &= rhs.mask()
+            }
+        }
+
+        impl Positive<$ty> {
+            /// The smallest power of two which is at least `self`.
+            ///
+            /// # Panics
+            /// - If the result would overflow.
+            pub fn next_power_of_two(self) -> PowerOfTwo<$ty> {
+                self.checked_next_power_of_two()
+                    .expect("next power of two overflowed")
+            }
+            /// The smallest power of two which is at least `self`, or [`None`]
+            /// if it would overflow.
+            pub fn checked_next_power_of_two(self) -> Option<PowerOfTwo<$ty>> {
+                self.0.checked_next_power_of_two().map(PowerOfTwo::new_unchecked)
+            }
+        }
+    )*};
+}
+
+// Shifts and masks only agree with `/` and `%` for non-negative numbers.
+power_of_two!(u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,81 @@
+//! [`proptest::arbitrary::Arbitrary`] implementations which generate in-range
+//! values directly, and shrink towards `1` or `-1`.
+
+use proptest::{
+    arbitrary::Arbitrary,
+    strategy::{BoxedStrategy, Strategy as _},
+};
+
+use crate::{Negative, Positive};
+
+macro_rules! arbitrary_unsigned {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Arbitrary for Positive<$ty> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                (1..=<$ty>::MAX).prop_map(Self::new_unchecked).boxed()
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_signed {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Arbitrary for Positive<$ty> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                (1..=<$ty>::MAX).prop_map(Self::new_unchecked).boxed()
+            }
+        }
+
+        impl Arbitrary for Negative<$ty> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                // `MIN..=-1` shrinks towards `-2`, so map `[0, MAX]` onto
+                // `[-1, MIN]` instead.
+                (0..=<$ty>::MAX).prop_map(|it| Self::new_unchecked(!it)).boxed()
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_float {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Arbitrary for Positive<$ty> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                // Range strategies shrink towards their lower bound, so prefer
+                // `[1, MAX]`, and fall back to `(0, 1)`.
+                proptest::prop_oneof![
+                    1.0..=<$ty>::MAX,
+                    <$ty>::from_bits(1)..1.0,
+                ]
+                .prop_map(Self::new_unchecked)
+                .boxed()
+            }
+        }
+
+        impl Arbitrary for Negative<$ty> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                proptest::arbitrary::any::<Positive<$ty>>()
+                    .prop_map(|it| -it)
+                    .boxed()
+            }
+        }
+    )*};
+}
+
+arbitrary_unsigned!(u8, u16, u32, u64, u128, usize);
+arbitrary_signed!(i8, i16, i32, i64, i128, isize);
+arbitrary_float!(f32, f64);
@@ -0,0 +1,95 @@
+//! [`quickcheck::Arbitrary`] implementations which generate in-range values
+//! directly, and shrink towards `1` or `-1`.
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{Negative, Positive};
+
+macro_rules! arbitrary_unsigned {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Arbitrary for Positive<$ty> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Self::new_unchecked(<$ty>::arbitrary(g).max(1))
+            }
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new((self.0 - 1).shrink().map(|it| Self::new_unchecked(it + 1)))
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_signed {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Arbitrary for Positive<$ty> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Self::new_unchecked(match <$ty>::arbitrary(g) {
+                    0 => 1,
+                    it => it.checked_abs().unwrap_or(<$ty>::MAX),
+                })
+            }
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(
+                    (self.0 - 1)
+                        .shrink()
+                        .filter_map(|it| Self::new(it.checked_add(1)?).ok()),
+                )
+            }
+        }
+
+        impl Arbitrary for Negative<$ty> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Self::new_unchecked(match <$ty>::arbitrary(g) {
+                    0 => -1,
+                    it if it > 0 => -it,
+                    it => it,
+                })
+            }
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(
+                    (self.0 + 1)
+                        .shrink()
+                        .filter_map(|it| Self::new(it.checked_sub(1)?).ok()),
+                )
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_float {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Arbitrary for Positive<$ty> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                match <$ty>::arbitrary(g).abs() {
+                    it if it > 0.0 => Self::new_unchecked(it),
+                    // zero or NaN
+                    _ => Self::new_unchecked(1.0),
+                }
+            }
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                // Float shrinking truncates to an integer, so `self - 1` may
+                // have no candidates when it's fractional.
+                let one = (self.0 != 1.0).then(|| Self::new_unchecked(1.0));
+                Box::new(
+                    one.into_iter().chain(
+                        (self.0 - 1.0)
+                            .shrink()
+                            .filter_map(|it| Self::new(it + 1.0).ok()),
+                    ),
+                )
+            }
+        }
+
+        impl Arbitrary for Negative<$ty> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                -Positive::<$ty>::arbitrary(g)
+            }
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new((-*self).shrink().map(|it| -it))
+            }
+        }
+    )*};
+}
+
+arbitrary_unsigned!(u8, u16, u32, u64, u128, usize);
+arbitrary_signed!(i8, i16, i32, i64, i128, isize);
+arbitrary_float!(f32, f64);
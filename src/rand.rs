@@ -0,0 +1,194 @@
+//! [`rand`] distributions which sample witnesses directly, rather than by
+//! rejecting out-of-range values.
+
+use rand::{
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformSampler},
+        Distribution, OpenClosed01, Standard,
+    },
+    Rng,
+};
+
+use crate::{Negative, Positive};
+
+macro_rules! standard_unsigned {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Distribution<Positive<$ty>> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Positive<$ty> {
+                Positive::new_unchecked(rng.gen_range(1..=<$ty>::MAX))
+            }
+        }
+    )*};
+}
+
+macro_rules! standard_signed {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Distribution<Positive<$ty>> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Positive<$ty> {
+                Positive::new_unchecked(rng.gen_range(1..=<$ty>::MAX))
+            }
+        }
+
+        impl Distribution<Negative<$ty>> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Negative<$ty> {
+                Negative::new_unchecked(rng.gen_range(<$ty>::MIN..=-1))
+            }
+        }
+    )*};
+}
+
+macro_rules! standard_float {
+    ($($ty:ty),* $(,)?) => {$(
+        /// Samples from `(0, 1]`.
+        impl Distribution<Positive<$ty>> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Positive<$ty> {
+                Positive::new_unchecked(rng.sample(OpenClosed01))
+            }
+        }
+
+        /// Samples from `[-1, 0)`.
+        impl Distribution<Negative<$ty>> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Negative<$ty> {
+                -rng.sample::<Positive<$ty>, _>(Standard)
+            }
+        }
+    )*};
+}
+
+standard_unsigned!(u8, u16, u32, u64, u128, usize);
+standard_signed!(i8, i16, i32, i64, i128, isize);
+standard_float!(f32, f64);
+
+/// A [`UniformSampler`] for [`Positive`], which delegates to the sampler for `T`.
+///
+/// Both bounds are positive, so every sample is too.
+#[derive(Debug, Clone, Copy)]
+pub struct PositiveUniform<T: SampleUniform>(T::Sampler);
+
+impl<T> SampleUniform for Positive<T>
+where
+    T: SampleUniform,
+{
+    type Sampler = PositiveUniform<T>;
+}
+
+impl<T> UniformSampler for PositiveUniform<T>
+where
+    T: SampleUniform,
+{
+    type X = Positive<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(T::Sampler::new(low.borrow().inner(), high.borrow().inner()))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(T::Sampler::new_inclusive(
+            low.borrow().inner(),
+            high.borrow().inner(),
+        ))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Positive::new_unchecked(self.0.sample(rng))
+    }
+
+    fn sample_single<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> Self::X
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Positive::new_unchecked(T::Sampler::sample_single(
+            low.borrow().inner(),
+            high.borrow().inner(),
+            rng,
+        ))
+    }
+
+    fn sample_single_inclusive<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> Self::X
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Positive::new_unchecked(T::Sampler::sample_single_inclusive(
+            low.borrow().inner(),
+            high.borrow().inner(),
+            rng,
+        ))
+    }
+}
+
+/// A [`UniformSampler`] for [`Negative`], which delegates to the sampler for `T`.
+///
+/// Both bounds are negative, so every sample is too.
+#[derive(Debug, Clone, Copy)]
+pub struct NegativeUniform<T: SampleUniform>(T::Sampler);
+
+impl<T> SampleUniform for Negative<T>
+where
+    T: SampleUniform,
+{
+    type Sampler = NegativeUniform<T>;
+}
+
+impl<T> UniformSampler for NegativeUniform<T>
+where
+    T: SampleUniform,
+{
+    type X = Negative<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(T::Sampler::new(low.borrow().inner(), high.borrow().inner()))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(T::Sampler::new_inclusive(
+            low.borrow().inner(),
+            high.borrow().inner(),
+        ))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Negative::new_unchecked(self.0.sample(rng))
+    }
+
+    fn sample_single<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> Self::X
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Negative::new_unchecked(T::Sampler::sample_single(
+            low.borrow().inner(),
+            high.borrow().inner(),
+            rng,
+        ))
+    }
+
+    fn sample_single_inclusive<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> Self::X
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Negative::new_unchecked(T::Sampler::sample_single_inclusive(
+            low.borrow().inner(),
+            high.borrow().inner(),
+            rng,
+        ))
+    }
+}
@@ -0,0 +1,94 @@
+use num::{rational::Ratio, CheckedSub, Integer};
+
+use crate::{Negative, Positive};
+
+/// The numerator and denominator of `value`, with a positive denominator.
+///
+/// [`Ratio::new_raw`] may leave the denominator negative, and negating the
+/// parts may overflow, as in `new_raw(i32::MIN, -2)`, so they're reduced
+/// first.
+///
+/// # Panics
+/// - If the reduced parts still overflow, as in `new_raw(i32::MIN, -1)`.
+fn parts<T: Clone + Integer + CheckedSub>(value: &Ratio<T>) -> (T, T) {
+    let (numer, denom) = (value.numer().clone(), value.denom().clone());
+    if denom >= T::zero() {
+        return (numer, denom);
+    }
+    let gcd = numer.gcd(&denom);
+    let (numer, denom) = (numer / gcd.clone(), denom / gcd);
+    match (T::zero().checked_sub(&numer), T::zero().checked_sub(&denom)) {
+        (Some(numer), Some(denom)) => (numer, denom),
+        _ => panic!("the parts of the ratio overflow with a positive denominator"),
+    }
+}
+
+impl<T: Clone + Integer> Positive<Ratio<T>> {
+    /// `numer / denom`, in lowest terms.
+    pub fn from_parts(numer: Positive<T>, denom: Positive<T>) -> Self {
+        Self::new_unchecked(Ratio::new(numer.0, denom.0))
+    }
+    /// # Panics
+    /// - If it can't be represented with a positive denominator, as in
+    ///   `Ratio::new_raw(i32::MIN, -1)`.
+    pub fn numer(&self) -> Positive<T>
+    where
+        T: CheckedSub,
+    {
+        Positive::new_unchecked(parts(&self.0).0)
+    }
+    /// # Panics
+    /// - If it can't be represented with a positive denominator, as in
+    ///   `Ratio::new_raw(i32::MIN, -1)`.
+    pub fn denom(&self) -> Positive<T>
+    where
+        T: CheckedSub,
+    {
+        Positive::new_unchecked(parts(&self.0).1)
+    }
+    /// `1 / self`.
+    pub fn recip(&self) -> Self {
+        Self::new_unchecked(self.0.recip())
+    }
+}
+
+impl<T: Clone + Integer> Negative<Ratio<T>> {
+    /// `numer / denom`, in lowest terms.
+    pub fn from_parts(numer: Negative<T>, denom: Positive<T>) -> Self {
+        Self::new_unchecked(Ratio::new(numer.0, denom.0))
+    }
+    /// # Panics
+    /// - If it can't be represented with a positive denominator, as in
+    ///   `Ratio::new_raw(i32::MIN, -1)`.
+    pub fn numer(&self) -> Negative<T>
+    where
+        T: CheckedSub,
+    {
+        Negative::new_unchecked(parts(&self.0).0)
+    }
+    /// # Panics
+    /// - If it can't be represented with a positive denominator, as in
+    ///   `Ratio::new_raw(i32::MIN, -1)`.
+    pub fn denom(&self) -> Positive<T>
+    where
+        T: CheckedSub,
+    {
+        Positive::new_unchecked(parts(&self.0).1)
+    }
+    /// `1 / self`.
+    pub fn recip(&self) -> Self {
+        Self::new_unchecked(self.0.recip())
+    }
+}
+
+impl<T: Clone + Integer> From<Positive<T>> for Positive<Ratio<T>> {
+    fn from(value: Positive<T>) -> Self {
+        Self::new_unchecked(Ratio::from_integer(value.0))
+    }
+}
+
+impl<T: Clone + Integer> From<Negative<T>> for Negative<Ratio<T>> {
+    fn from(value: Negative<T>) -> Self {
+        Self::new_unchecked(Ratio::from_integer(value.0))
+    }
+}
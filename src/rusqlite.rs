@@ -0,0 +1,29 @@
+//! [`rusqlite`] implementations, which use the SQLite representation of `T`.
+//!
+//! Conversion fails with [`Rejected`](crate::Rejected) if the predicate doesn't
+//! hold for a column.
+
+use std::fmt;
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::{Predicate, Witness};
+
+impl<T, P> FromSql for Witness<T, P>
+where
+    T: FromSql + fmt::Display + fmt::Debug + Send + Sync + 'static,
+    P: Predicate<T> + 'static,
+{
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        Self::new(T::column_result(value)?).map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+impl<T, P> ToSql for Witness<T, P>
+where
+    T: ToSql,
+{
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        self.0.to_sql()
+    }
+}
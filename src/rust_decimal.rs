@@ -0,0 +1,24 @@
+//! [`rust_decimal`] rounding, which reports when the rounded value would break
+//! the witness, such as a [`Positive`](crate::Positive) rounding to zero.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::{Predicate, Rejected, Witness};
+
+impl<P> Witness<Decimal, P>
+where
+    P: Predicate<Decimal>,
+{
+    /// Round to `dp` decimal places, using banker's rounding.
+    pub fn round_dp(&self, dp: u32) -> Result<Self, Rejected<Decimal, P>> {
+        Self::new(self.0.round_dp(dp))
+    }
+    /// Round to `dp` decimal places, using `strategy`.
+    pub fn round_dp_with_strategy(
+        &self,
+        dp: u32,
+        strategy: RoundingStrategy,
+    ) -> Result<Self, Rejected<Decimal, P>> {
+        Self::new(self.0.round_dp_with_strategy(dp, strategy))
+    }
+}
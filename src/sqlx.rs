@@ -0,0 +1,53 @@
+//! [`sqlx`] implementations, which use the database representation of `T`.
+//!
+//! Decoding fails with [`Rejected`](crate::Rejected) if the predicate doesn't
+//! hold for a column.
+
+use std::fmt;
+
+use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+use crate::{Predicate, Witness};
+
+impl<DB, T, P> Type<DB> for Witness<T, P>
+where
+    DB: Database,
+    T: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+impl<'q, DB, T, P> Encode<'q, DB> for Witness<T, P>
+where
+    DB: Database,
+    T: Encode<'q, DB>,
+{
+    fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.0.encode(buf)
+    }
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        self.0.produces()
+    }
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl<'r, DB, T, P> Decode<'r, DB> for Witness<T, P>
+where
+    DB: Database,
+    T: Decode<'r, DB> + fmt::Display + fmt::Debug + Send + Sync + 'static,
+    P: Predicate<T> + 'static,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(Self::new(T::decode(value)?)?)
+    }
+}
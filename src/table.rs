@@ -0,0 +1,167 @@
+//! Macros which implement a row of an operation table, for owned and borrowed
+//! operands alike.
+//!
+//! A row is written as it appears in the table, e.g
+//! `binop!(Add::add, Positive, impl Unsigned => Positive)`, where an output of
+//! `?` is the inner type.
+
+/// `Lhs op Rhs`, `Lhs op &Rhs`, `&Lhs op Rhs` and `&Lhs op &Rhs`, or just
+/// `Lhs op Rhs` for a primitive `Lhs`, which must be commutative.
+///
+/// An owned `impl Unsigned` goes through the operators in [`crate::unsigned`],
+/// so it may be mixed with a signed integer of the same width.
+/// A borrowed one would overlap with it, so borrowed unsigned operands are
+/// implemented for each unsigned type instead.
+///
+/// `impl Float` is implemented for `f32` and `f64`.
+macro_rules! binop {
+    ($Op:ident::$op:ident, impl Unsigned, $Rhs:ident => $($Out:tt)+) => {
+        binop!(@primitive [crate::unsigned] $Op::$op, $Rhs, [$($Out)+], u8, u16, u32, u64, u128, usize);
+    };
+    ($Op:ident::$op:ident, impl Float, $Rhs:ident => $($Out:tt)+) => {
+        binop!(@primitive [std::ops] $Op::$op, $Rhs, [$($Out)+], f32, f64);
+    };
+    ($Op:ident::$op:ident, $Lhs:ident, impl Float => $($Out:tt)+) => {
+        binop!(@float $Op::$op, $Lhs, [$($Out)+], f32, f64);
+    };
+    ($Op:ident::$op:ident, $Lhs:ident, impl Unsigned => $($Out:tt)+) => {
+        binop!(@impl [crate::unsigned] $Op::$op, [LhsT, RhsT, OutT], $Lhs<LhsT>, [.into_inner()], LhsT, RhsT, [], RhsT, [RhsT: crate::Unsigned], [$($Out)+]);
+        binop!(@impl [crate::unsigned] $Op::$op, ['a, LhsT, RhsT, OutT], &'a $Lhs<LhsT>, [.inner()], &'a LhsT, RhsT, [], RhsT, [RhsT: crate::Unsigned], [$($Out)+]);
+        binop!(@borrowed $Op::$op, $Lhs, [$($Out)+], u8, u16, u32, u64, u128, usize, num::BigUint);
+    };
+    ($Op:ident::$op:ident, $Lhs:ident, $Rhs:ident => $($Out:tt)+) => {
+        binop!(@impl [std::ops] $Op::$op, [LhsT, RhsT, OutT], $Lhs<LhsT>, [.into_inner()], LhsT, $Rhs<RhsT>, [.into_inner()], RhsT, [], [$($Out)+]);
+        binop!(@impl [std::ops] $Op::$op, ['b, LhsT, RhsT, OutT], $Lhs<LhsT>, [.into_inner()], LhsT, &'b $Rhs<RhsT>, [.inner()], &'b RhsT, [], [$($Out)+]);
+        binop!(@impl [std::ops] $Op::$op, ['a, LhsT, RhsT, OutT], &'a $Lhs<LhsT>, [.inner()], &'a LhsT, $Rhs<RhsT>, [.into_inner()], RhsT, [], [$($Out)+]);
+        binop!(@impl [std::ops] $Op::$op, ['a, 'b, LhsT, RhsT, OutT], &'a $Lhs<LhsT>, [.inner()], &'a LhsT, &'b $Rhs<RhsT>, [.inner()], &'b RhsT, [], [$($Out)+]);
+    };
+    (@borrowed $Op:ident::$op:ident, $Lhs:ident, $out:tt, $($U:ty),*) => {$(
+        binop!(@impl [std::ops] $Op::$op, ['b, LhsT, OutT], $Lhs<LhsT>, [.into_inner()], LhsT, &'b $U, [], &'b $U, [], $out);
+        binop!(@impl [std::ops] $Op::$op, ['a, 'b, LhsT, OutT], &'a $Lhs<LhsT>, [.inner()], &'a LhsT, &'b $U, [], &'b $U, [], $out);
+    )*};
+    (@float $Op:ident::$op:ident, $Lhs:ident, $out:tt, $($F:ty),*) => {$(
+        binop!(@impl [std::ops] $Op::$op, [LhsT, OutT], $Lhs<LhsT>, [.into_inner()], LhsT, $F, [], $F, [], $out);
+        binop!(@impl [std::ops] $Op::$op, ['a, LhsT, OutT], &'a $Lhs<LhsT>, [.inner()], &'a LhsT, $F, [], $F, [], $out);
+        binop!(@borrowed $Op::$op, $Lhs, $out, $F);
+    )*};
+    // A primitive left hand side, which is swapped onto the right hand side,
+    // so it's the same as the `impl Unsigned` or `impl Float` rows.
+    // Orphan rules forbid a blanket implementation.
+    (@primitive $ops:tt $Op:ident::$op:ident, $Rhs:ident, $out:tt, $($P:ty),*) => {$(
+        binop!(@swap $ops $Op::$op, $Rhs, $out, $P);
+    )*};
+    (@swap [$($ops:tt)*] $Op:ident::$op:ident, $Rhs:ident, [?], $P:ty) => {
+        impl<RhsT, OutT> std::ops::$Op<$Rhs<RhsT>> for $P
+        where
+            RhsT: $($ops)*::$Op<$P, Output = OutT>,
+        {
+            type Output = OutT;
+
+            fn $op(self, rhs: $Rhs<RhsT>) -> Self::Output {
+                $($ops)*::$Op::$op(rhs.into_inner(), self)
+            }
+        }
+    };
+    (@swap [$($ops:tt)*] $Op:ident::$op:ident, $Rhs:ident, [$Out:ident], $P:ty) => {
+        impl<RhsT, OutT> std::ops::$Op<$Rhs<RhsT>> for $P
+        where
+            RhsT: $($ops)*::$Op<$P, Output = OutT>,
+        {
+            type Output = $Out<OutT>;
+
+            fn $op(self, rhs: $Rhs<RhsT>) -> Self::Output {
+                Self::Output::new_unchecked($($ops)*::$Op::$op(rhs.into_inner(), self))
+            }
+        }
+    };
+    (@impl [$($ops:tt)*] $Op:ident::$op:ident, [$($generics:tt)*],
+        $lhs:ty, [$($lhs_access:tt)*], $lhs_inner:ty,
+        $rhs:ty, [$($rhs_access:tt)*], $rhs_inner:ty,
+        [$($bound:tt)*], [?]
+    ) => {
+        impl<$($generics)*> std::ops::$Op<$rhs> for $lhs
+        where
+            $lhs_inner: $($ops)*::$Op<$rhs_inner, Output = OutT>,
+            $($bound)*
+        {
+            type Output = OutT;
+
+            fn $op(self, rhs: $rhs) -> Self::Output {
+                $($ops)*::$Op::$op(self $($lhs_access)*, rhs $($rhs_access)*)
+            }
+        }
+    };
+    (@impl [$($ops:tt)*] $Op:ident::$op:ident, [$($generics:tt)*],
+        $lhs:ty, [$($lhs_access:tt)*], $lhs_inner:ty,
+        $rhs:ty, [$($rhs_access:tt)*], $rhs_inner:ty,
+        [$($bound:tt)*], [$Out:ident]
+    ) => {
+        impl<$($generics)*> std::ops::$Op<$rhs> for $lhs
+        where
+            $lhs_inner: $($ops)*::$Op<$rhs_inner, Output = OutT>,
+            $($bound)*
+        {
+            type Output = $Out<OutT>;
+
+            fn $op(self, rhs: $rhs) -> Self::Output {
+                Self::Output::new_unchecked($($ops)*::$Op::$op(self $($lhs_access)*, rhs $($rhs_access)*))
+            }
+        }
+    };
+}
+
+/// `lhs op= rhs` and `lhs op= &rhs`.
+macro_rules! assign {
+    ($OpAssign:ident::$op_assign:ident, $Lhs:ident, impl Unsigned) => {
+        assign!(@impl [crate::unsigned] $OpAssign::$op_assign, [LhsT, RhsT], $Lhs, RhsT, [], RhsT, [RhsT: crate::Unsigned]);
+        assign!(@borrowed $OpAssign::$op_assign, $Lhs, u8, u16, u32, u64, u128, usize, num::BigUint);
+    };
+    ($OpAssign:ident::$op_assign:ident, $Lhs:ident, $Rhs:ident) => {
+        assign!(@impl [std::ops] $OpAssign::$op_assign, [LhsT, RhsT], $Lhs, $Rhs<RhsT>, [.into_inner()], RhsT, []);
+        assign!(@impl [std::ops] $OpAssign::$op_assign, ['b, LhsT, RhsT], $Lhs, &'b $Rhs<RhsT>, [.inner()], &'b RhsT, []);
+    };
+    (@borrowed $OpAssign:ident::$op_assign:ident, $Lhs:ident, $($U:ty),*) => {$(
+        assign!(@impl [std::ops] $OpAssign::$op_assign, ['b, LhsT], $Lhs, &'b $U, [], &'b $U, []);
+    )*};
+    (@impl [$($ops:tt)*] $OpAssign:ident::$op_assign:ident, [$($generics:tt)*],
+        $Lhs:ident, $rhs:ty, [$($rhs_access:tt)*], $rhs_inner:ty,
+        [$($bound:tt)*]
+    ) => {
+        impl<$($generics)*> std::ops::$OpAssign<$rhs> for $Lhs<LhsT>
+        where
+            LhsT: $($ops)*::$OpAssign<$rhs_inner>,
+            $($bound)*
+        {
+            fn $op_assign(&mut self, rhs: $rhs) {
+                $($ops)*::$OpAssign::$op_assign(self.mut_unchecked(), rhs $($rhs_access)*)
+            }
+        }
+    };
+}
+
+/// `-operand` and `-&operand`.
+macro_rules! neg {
+    ($T:ident => $Out:ident) => {
+        impl<T, U> std::ops::Neg for $T<T>
+        where
+            T: std::ops::Neg<Output = U>,
+        {
+            type Output = $Out<U>;
+
+            fn neg(self) -> Self::Output {
+                Self::Output::new_unchecked(-self.into_inner())
+            }
+        }
+
+        impl<'a, T, U> std::ops::Neg for &'a $T<T>
+        where
+            &'a T: std::ops::Neg<Output = U>,
+        {
+            type Output = $Out<U>;
+
+            fn neg(self) -> Self::Output {
+                Self::Output::new_unchecked(-self.inner())
+            }
+        }
+    };
+}
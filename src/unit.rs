@@ -0,0 +1,224 @@
+use std::{fmt, ops};
+
+use num::Float;
+
+use crate::{
+    Describe, Given, Implies, IsPositive, Negative, Not, NotPositive, Positive, Predicate,
+    Rejected, Witness,
+};
+
+/// A [`Predicate`] that a float is in `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsProbability;
+
+/// A [`Predicate`] that a float is in `(0, 1]`.
+///
+/// This [`Implies`] [`IsPositive`] and [`IsProbability`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsPositiveProbability;
+
+/// A [`Predicate`] that a float is in `(0, 1)`.
+///
+/// This [`Implies`] [`IsPositive`], [`IsProbability`] and
+/// [`IsPositiveProbability`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IsOpenUnit;
+
+impl Describe for IsProbability {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Probability")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("in [0, 1]")
+    }
+}
+
+impl<F: Float> Predicate<F> for IsProbability {
+    fn test(value: &F) -> bool {
+        F::zero() <= *value && *value <= F::one()
+    }
+}
+
+impl Describe for IsPositiveProbability {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PositiveProbability")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("in (0, 1]")
+    }
+}
+
+impl<F: Float> Predicate<F> for IsPositiveProbability {
+    fn test(value: &F) -> bool {
+        F::zero() < *value && *value <= F::one()
+    }
+}
+
+impl Describe for IsOpenUnit {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OpenUnit")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("in (0, 1)")
+    }
+}
+
+impl<F: Float> Predicate<F> for IsOpenUnit {
+    fn test(value: &F) -> bool {
+        F::zero() < *value && *value < F::one()
+    }
+}
+
+impl Implies<IsPositive, Given> for IsOpenUnit {}
+
+impl Implies<IsProbability, Given> for IsOpenUnit {}
+
+impl Implies<IsPositiveProbability, Given> for IsOpenUnit {}
+
+impl Implies<IsPositive, Given> for IsPositiveProbability {}
+
+impl Implies<IsProbability, Given> for IsPositiveProbability {}
+
+/// A guarantee that `F` is in `[0, 1]`.
+pub type Probability<F> = Witness<F, IsProbability>;
+
+pub type NotProbability<F> = Rejected<F, IsProbability>;
+
+/// A guarantee that `F` is in `(0, 1]`.
+pub type PositiveProbability<F> = Witness<F, IsPositiveProbability>;
+
+pub type NotPositiveProbability<F> = Rejected<F, IsPositiveProbability>;
+
+/// A guarantee that `F` is in `(0, 1)`.
+pub type OpenUnit<F> = Witness<F, IsOpenUnit>;
+
+pub type NotOpenUnit<F> = Rejected<F, IsOpenUnit>;
+
+impl<F: Float> Probability<F> {
+    /// `1 - self`.
+    pub fn complement(self) -> Self {
+        Self::new_unchecked(F::one() - self.0)
+    }
+}
+
+impl<F: Float> PositiveProbability<F> {
+    /// `1 - self`.
+    ///
+    /// This is a [`Probability`], because `1 - 1` is zero.
+    pub fn complement(self) -> Probability<F> {
+        Probability::new_unchecked(F::one() - self.0)
+    }
+    /// The natural logarithm, which isn't positive, because `ln(1)` is zero.
+    pub fn ln(self) -> Witness<F, Not<IsPositive>> {
+        Witness::new_unchecked(self.0.ln())
+    }
+}
+
+impl<F: Float> OpenUnit<F> {
+    /// `1 - self`.
+    ///
+    /// This is a [`Probability`] rather than an [`OpenUnit`], because `1 - self`
+    /// rounds to `1` when `self` is very small.
+    pub fn complement(self) -> Probability<F> {
+        Probability::new_unchecked(F::one() - self.0)
+    }
+    /// The natural logarithm, which is negative.
+    pub fn ln(self) -> Negative<F> {
+        Negative::new_unchecked(self.0.ln())
+    }
+}
+
+// | Operation | LHS           | RHS           | Output        | Assignable? |
+// | --------- | ------------- | ------------- | ------------- | ----------- |
+// | Mul       | `Probability` | `Probability` | `Probability` | Yes         |
+impl<F: Float> ops::Mul for Probability<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new_unchecked(self.0 * rhs.0)
+    }
+}
+
+impl<F: Float> ops::MulAssign for Probability<F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+// | Operation | LHS        | RHS        | Output        | Assignable? |
+// | --------- | ---------- | ---------- | ------------- | ----------- |
+// | Mul       | `OpenUnit` | `OpenUnit` | `Probability` | No          |
+impl<F: Float> ops::Mul for OpenUnit<F> {
+    type Output = Probability<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Probability::new_unchecked(self.0 * rhs.0)
+    }
+}
+
+// | Operation | LHS                   | RHS                   | Output        | Assignable? |
+// | --------- | --------------------- | --------------------- | ------------- | ----------- |
+// | Mul       | `PositiveProbability` | `PositiveProbability` | `Probability` | No          |
+impl<F: Float> ops::Mul for PositiveProbability<F> {
+    type Output = Probability<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Probability::new_unchecked(self.0 * rhs.0)
+    }
+}
+
+impl<F: Float> PositiveProbability<F> {
+    /// `self * rhs`, or [`None`] if the product underflows to zero.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0 * rhs.0).ok()
+    }
+}
+
+impl<F: Float> OpenUnit<F> {
+    /// `self * rhs`, or [`None`] if the product underflows to zero.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0 * rhs.0).ok()
+    }
+}
+
+/////////////////
+// Conversions //
+/////////////////
+
+impl<F> From<OpenUnit<F>> for Positive<F> {
+    fn from(value: OpenUnit<F>) -> Self {
+        value.weaken()
+    }
+}
+
+impl<F> From<OpenUnit<F>> for Probability<F> {
+    fn from(value: OpenUnit<F>) -> Self {
+        value.weaken()
+    }
+}
+
+impl<F> From<OpenUnit<F>> for PositiveProbability<F> {
+    fn from(value: OpenUnit<F>) -> Self {
+        value.weaken()
+    }
+}
+
+impl<F> From<PositiveProbability<F>> for Positive<F> {
+    fn from(value: PositiveProbability<F>) -> Self {
+        value.weaken()
+    }
+}
+
+impl<F> From<PositiveProbability<F>> for Probability<F> {
+    fn from(value: PositiveProbability<F>) -> Self {
+        value.weaken()
+    }
+}
+
+impl<F: Float> TryFrom<Probability<F>> for Positive<F> {
+    type Error = NotPositive<F>;
+
+    fn try_from(value: Probability<F>) -> Result<Self, Self::Error> {
+        Self::new(value.into_inner())
+    }
+}
@@ -0,0 +1,260 @@
+//! Operators for the `impl Unsigned` rows, which mirror [`std::ops`].
+//!
+//! They're implemented for
+//! - any [`Unsigned`] type with itself,
+//! - a signed integer with the unsigned integer of the same width,
+//! - [`num::BigUint`] and [`num::BigInt`] with any primitive unsigned integer,
+//! - a [`num::rational::Ratio`] of an unsigned integer with that integer,
+//!
+//! so an unsuffixed literal on the right hand side of a primitive row still
+//! infers to a single type.
+//!
+//! The traits are public, but can't be named outside this crate.
+
+use crate::Unsigned;
+
+macro_rules! ops {
+    ($($Op:ident::$op:ident),* $(,)?) => {$(
+        pub trait $Op<Rhs> {
+            type Output;
+            fn $op(self, rhs: Rhs) -> Self::Output;
+        }
+
+        impl<T> $Op<T> for T
+        where
+            T: Unsigned,
+        {
+            type Output = T;
+
+            fn $op(self, rhs: T) -> T {
+                std::ops::$Op::$op(self, rhs)
+            }
+        }
+    )*};
+}
+
+/// Only for the assignable rows.
+macro_rules! ops_assign {
+    ($($OpAssign:ident::$op_assign:ident),* $(,)?) => {$(
+        pub trait $OpAssign<Rhs> {
+            fn $op_assign(&mut self, rhs: Rhs);
+        }
+
+        impl<T> $OpAssign<T> for T
+        where
+            T: Unsigned + std::ops::$OpAssign,
+        {
+            fn $op_assign(&mut self, rhs: T) {
+                std::ops::$OpAssign::$op_assign(self, rhs)
+            }
+        }
+    )*};
+}
+
+ops!(Add::add, Sub::sub, Mul::mul, Div::div);
+ops_assign!(
+    AddAssign::add_assign,
+    SubAssign::sub_assign,
+    DivAssign::div_assign
+);
+
+/// Forward to [`std::ops`], for a borrowed unsigned left hand side and the
+/// big integers.
+macro_rules! forward {
+    ($($Lhs:ty => $($Rhs:ty),*;)*) => {$($(
+        forward!(@op [] $Lhs, $Rhs, $Lhs);
+        forward!(@op ['a] &'a $Lhs, $Rhs, $Lhs);
+        forward!(@assign $Lhs, $Rhs);
+    )*)*};
+    (@op $generics:tt $lhs:ty, $Rhs:ty, $Out:ty) => {
+        forward!(@op $generics $lhs, $Rhs, $Out, Add::add);
+        forward!(@op $generics $lhs, $Rhs, $Out, Sub::sub);
+        forward!(@op $generics $lhs, $Rhs, $Out, Mul::mul);
+        forward!(@op $generics $lhs, $Rhs, $Out, Div::div);
+    };
+    (@op [$($generics:tt)*] $lhs:ty, $Rhs:ty, $Out:ty, $Op:ident::$op:ident) => {
+        impl<$($generics)*> $Op<$Rhs> for $lhs {
+            type Output = $Out;
+
+            fn $op(self, rhs: $Rhs) -> $Out {
+                std::ops::$Op::$op(self, rhs)
+            }
+        }
+    };
+    (@assign $Lhs:ty, $Rhs:ty) => {
+        forward!(@assign $Lhs, $Rhs, AddAssign::add_assign, SubAssign::sub_assign, DivAssign::div_assign);
+    };
+    (@assign $Lhs:ty, $Rhs:ty, $($OpAssign:ident::$op_assign:ident),*) => {$(
+        impl $OpAssign<$Rhs> for $Lhs {
+            fn $op_assign(&mut self, rhs: $Rhs) {
+                std::ops::$OpAssign::$op_assign(self, rhs)
+            }
+        }
+    )*};
+}
+
+/// Just the borrowed left hand side, the owned one is covered by the blanket
+/// implementations.
+macro_rules! forward_ref {
+    ($($T:ty),*) => {$(
+        forward!(@op ['a] &'a $T, $T, $T);
+    )*};
+}
+
+forward_ref!(u8, u16, u32, u64, u128, usize, num::BigUint);
+
+forward! {
+    num::BigUint => u8, u16, u32, u64, u128, usize;
+    num::BigInt => u8, u16, u32, u64, u128, usize;
+}
+
+forward! {
+    num::rational::Ratio<u8> => u8;
+    num::rational::Ratio<u16> => u16;
+    num::rational::Ratio<u32> => u32;
+    num::rational::Ratio<u64> => u64;
+    num::rational::Ratio<u128> => u128;
+    num::rational::Ratio<usize> => usize;
+}
+
+/// Like the primitive operators, panic on overflow in debug builds, and wrap
+/// otherwise.
+macro_rules! overflowing {
+    ($checked:expr, $wrapping:expr, $msg:literal) => {
+        match $checked {
+            Some(it) => it,
+            None if cfg!(debug_assertions) => panic!($msg),
+            None => $wrapping,
+        }
+    };
+}
+
+/// A signed integer with the unsigned integer of the same width.
+///
+/// Like the primitive operators, these panic on overflow in debug builds and
+/// wrap in release builds, and always panic on division by zero.
+macro_rules! signed {
+    ($($I:ty => $U:ty),* $(,)?) => {$(
+        impl Add<$U> for $I {
+            type Output = $I;
+
+            fn add(self, rhs: $U) -> $I {
+                overflowing!(
+                    self.checked_add_unsigned(rhs),
+                    self.wrapping_add_unsigned(rhs),
+                    "attempt to add with overflow"
+                )
+            }
+        }
+
+        impl Sub<$U> for $I {
+            type Output = $I;
+
+            fn sub(self, rhs: $U) -> $I {
+                overflowing!(
+                    self.checked_sub_unsigned(rhs),
+                    self.wrapping_sub_unsigned(rhs),
+                    "attempt to subtract with overflow"
+                )
+            }
+        }
+
+        impl Mul<$U> for $I {
+            type Output = $I;
+
+            fn mul(self, rhs: $U) -> $I {
+                overflowing!(
+                    self.unsigned_abs()
+                        .checked_mul(rhs)
+                        .and_then(|magnitude| {
+                            if self < 0 {
+                                <$I>::checked_sub_unsigned(0, magnitude)
+                            } else {
+                                <$I>::try_from(magnitude).ok()
+                            }
+                        }),
+                    // Two's complement multiplication wraps the same way
+                    // whatever the signedness.
+                    self.wrapping_mul(rhs as $I),
+                    "attempt to multiply with overflow"
+                )
+            }
+        }
+
+        impl Div<$U> for $I {
+            type Output = $I;
+
+            fn div(self, rhs: $U) -> $I {
+                let magnitude = self
+                    .unsigned_abs()
+                    .checked_div(rhs)
+                    .expect("attempt to divide by zero");
+                // The magnitude can't grow, so it fits (`MIN / 1` wraps to `MIN`).
+                if self < 0 {
+                    (magnitude as $I).wrapping_neg()
+                } else {
+                    magnitude as $I
+                }
+            }
+        }
+
+        impl<'a> Add<$U> for &'a $I {
+            type Output = $I;
+
+            fn add(self, rhs: $U) -> $I {
+                Add::add(*self, rhs)
+            }
+        }
+
+        impl<'a> Sub<$U> for &'a $I {
+            type Output = $I;
+
+            fn sub(self, rhs: $U) -> $I {
+                Sub::sub(*self, rhs)
+            }
+        }
+
+        impl<'a> Mul<$U> for &'a $I {
+            type Output = $I;
+
+            fn mul(self, rhs: $U) -> $I {
+                Mul::mul(*self, rhs)
+            }
+        }
+
+        impl<'a> Div<$U> for &'a $I {
+            type Output = $I;
+
+            fn div(self, rhs: $U) -> $I {
+                Div::div(*self, rhs)
+            }
+        }
+
+        impl AddAssign<$U> for $I {
+            fn add_assign(&mut self, rhs: $U) {
+                *self = Add::add(*self, rhs)
+            }
+        }
+
+        impl SubAssign<$U> for $I {
+            fn sub_assign(&mut self, rhs: $U) {
+                *self = Sub::sub(*self, rhs)
+            }
+        }
+
+        impl DivAssign<$U> for $I {
+            fn div_assign(&mut self, rhs: $U) {
+                *self = Div::div(*self, rhs)
+            }
+        }
+    )*};
+}
+
+signed!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+);
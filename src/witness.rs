@@ -0,0 +1,335 @@
+use std::{cmp, fmt, hash, marker::PhantomData};
+
+/// How a [`Predicate`] is formatted.
+pub trait Describe {
+    /// Used when formatting a [`Witness`], e.g `Positive`.
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    /// Used when formatting a [`Rejected`] value, e.g `positive`.
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// A property of a `T`, which a [`Witness`] guarantees.
+///
+/// ```
+/// use std::fmt;
+///
+/// use numwit::{Describe, Predicate, Witness};
+///
+/// struct IsEven;
+///
+/// impl Describe for IsEven {
+///     fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("Even")
+///     }
+///     fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("even")
+///     }
+/// }
+///
+/// impl Predicate<u32> for IsEven {
+///     fn test(value: &u32) -> bool {
+///         value.is_multiple_of(2)
+///     }
+/// }
+///
+/// type Even = Witness<u32, IsEven>;
+///
+/// assert_eq!(format!("{:?}", Even::new(2).unwrap()), "Even(2)");
+/// assert_eq!(Even::new(3).unwrap_err().to_string(), "The value 3 was not even");
+/// ```
+pub trait Predicate<T>: Describe {
+    fn test(value: &T) -> bool;
+    /// Formats why `value` was rejected, which is [`Describe::description`]
+    /// unless a more specific reason is known.
+    fn reason(value: &T) -> fn(&mut fmt::Formatter<'_>) -> fmt::Result {
+        let _ = value;
+        Self::description
+    }
+}
+
+/// Formats with [`Describe::name`].
+pub(crate) struct Name<P>(PhantomData<fn() -> P>);
+
+impl<P> Name<P> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: Describe> fmt::Display for Name<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        P::name(f)
+    }
+}
+
+/// A guarantee that `P` holds for `T`.
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
+#[repr(transparent)]
+pub struct Witness<T, P>(pub(crate) T, PhantomData<fn() -> P>);
+
+impl<T, P> Witness<T, P> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+    pub fn inner(&self) -> &T {
+        self.as_ref()
+    }
+    pub const fn new_unchecked(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+    pub fn map_unchecked(self, mut f: impl FnMut(T) -> T) -> Self {
+        Self::new_unchecked(f(self.0))
+    }
+    pub fn mut_unchecked(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, P> Witness<T, P>
+where
+    P: Predicate<T>,
+{
+    pub fn new(value: T) -> Result<Self, Rejected<T, P>> {
+        match P::test(&value) {
+            true => Ok(Self::new_unchecked(value)),
+            false => Err(Rejected::because(P::reason(&value), value)),
+        }
+    }
+    pub fn map(self, mut f: impl FnMut(T) -> T) -> Result<Self, Rejected<T, P>> {
+        Self::new(f(self.0))
+    }
+    /// # Panics
+    /// - If `P` doesn't hold.
+    pub fn assert(&self) {
+        assert!(
+            P::test(&self.0),
+            "value was not {}",
+            Description::<P>::new()
+        );
+    }
+    /// # Panics
+    /// - If `P` doesn't hold.
+    pub fn debug_assert(&self) {
+        debug_assert!(
+            P::test(&self.0),
+            "value was not {}",
+            Description::<P>::new()
+        );
+    }
+}
+
+// Implemented by hand, so that `P` needn't implement them.
+
+impl<T: Clone, P> Clone for Witness<T, P> {
+    fn clone(&self) -> Self {
+        Self::new_unchecked(self.0.clone())
+    }
+}
+
+impl<T: Copy, P> Copy for Witness<T, P> {}
+
+impl<T: PartialEq, P> PartialEq for Witness<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<T: Eq, P> Eq for Witness<T, P> {}
+
+impl<T: PartialOrd, P> PartialOrd for Witness<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: Ord, P> Ord for Witness<T, P> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: hash::Hash, P> hash::Hash for Witness<T, P> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T, P> AsRef<T> for Witness<T, P> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug, P: Describe> fmt::Debug for Witness<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(&Name::<P>::new().to_string())
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<T: fmt::Display, P> fmt::Display for Witness<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T, P> PartialEq<T> for Witness<T, P>
+where
+    T: PartialEq<T>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/// Formats with [`Describe::description`].
+pub(crate) struct Description<P>(PhantomData<fn() -> P>);
+
+impl<P> Description<P> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: Describe> fmt::Display for Description<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        P::description(f)
+    }
+}
+
+/// A value for which `P` didn't hold.
+pub struct Rejected<T, P>(
+    pub T,
+    fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    PhantomData<fn() -> P>,
+);
+
+impl<T, P> Rejected<T, P> {
+    pub fn new(value: T) -> Self
+    where
+        P: Describe,
+    {
+        Self::because(P::description, value)
+    }
+    pub(crate) fn because(reason: fn(&mut fmt::Formatter<'_>) -> fmt::Result, value: T) -> Self {
+        Self(value, reason, PhantomData)
+    }
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: fmt::Debug, P> fmt::Debug for Rejected<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Rejected").field(&self.0).finish()
+    }
+}
+
+impl<T: fmt::Display, P> fmt::Display for Rejected<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("The value {} was not ", self.0))?;
+        (self.1)(f)
+    }
+}
+
+impl<T: fmt::Display + fmt::Debug, P> std::error::Error for Rejected<T, P> {}
+
+impl<'de, T, P> serde::Deserialize<'de> for Witness<T, P>
+where
+    T: serde::Deserialize<'de> + fmt::Display,
+    P: Predicate<T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let t = T::deserialize(deserializer)?;
+        Self::new(t).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<T, P> serde::Serialize for Witness<T, P>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+////////////
+// Slices //
+////////////
+
+/// Returns the index of the first item in `slice` which isn't `valid`.
+///
+/// Each chunk is checked without short-circuiting, which allows the check to be
+/// vectorized for primitives.
+fn first_invalid<T>(slice: &[T], valid: impl Fn(&T) -> bool) -> Option<usize> {
+    const CHUNK: usize = 64;
+    slice
+        .chunks(CHUNK)
+        .enumerate()
+        .find_map(
+            |(chunk_ix, chunk)| match chunk.iter().fold(true, |acc, it| acc & valid(it)) {
+                true => None,
+                false => chunk
+                    .iter()
+                    .position(|it| !valid(it))
+                    .map(|ix| chunk_ix * CHUNK + ix),
+            },
+        )
+}
+
+impl<T, P> Witness<T, P> {
+    /// Reinterpret witnesses as their inner values, without copying.
+    pub fn as_inner_slice(slice: &[Self]) -> &[T] {
+        // SAFETY: `Witness` is `#[repr(transparent)]`.
+        unsafe { &*(slice as *const [Self] as *const [T]) }
+    }
+    /// Unwrap witnesses, without reallocating.
+    pub fn into_inner_vec(vec: Vec<Self>) -> Vec<T> {
+        let mut vec = std::mem::ManuallyDrop::new(vec);
+        // SAFETY: `Witness` is `#[repr(transparent)]`, so has the same layout as `T`.
+        unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+    }
+}
+
+impl<T, P> Witness<T, P>
+where
+    P: Predicate<T>,
+{
+    /// Reinterpret `slice` as witnesses, without copying.
+    ///
+    /// On failure, returns the index of the first value for which `P` doesn't hold.
+    pub fn slice_from(slice: &[T]) -> Result<&[Self], (usize, Rejected<&T, P>)> {
+        match first_invalid(slice, P::test) {
+            Some(ix) => Err((ix, Rejected::because(P::reason(&slice[ix]), &slice[ix]))),
+            // SAFETY: `Witness` is `#[repr(transparent)]`, and `P` holds for every element.
+            None => Ok(unsafe { &*(slice as *const [T] as *const [Self]) }),
+        }
+    }
+    /// Wrap every value in `vec`, without reallocating.
+    ///
+    /// On failure, returns the index of the first value for which `P` doesn't
+    /// hold, and the original `vec`.
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Vec<Self>, (usize, Vec<T>)> {
+        match first_invalid(&vec, P::test) {
+            Some(ix) => Err((ix, vec)),
+            None => {
+                let mut vec = std::mem::ManuallyDrop::new(vec);
+                // SAFETY: `Witness` is `#[repr(transparent)]`, and `P` holds for every element.
+                Ok(unsafe {
+                    Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity())
+                })
+            }
+        }
+    }
+}
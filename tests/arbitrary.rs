@@ -0,0 +1,241 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use num::rational::Ratio;
+use numwit::{Negative, Positive};
+
+/// Run `f` against many pseudo-random inputs, as `cargo fuzz` would.
+fn fuzz(mut f: impl FnMut(&mut Unstructured) -> Result<()>) {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut data = [0; 64];
+    for _ in 0..10_000 {
+        for byte in &mut data {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *byte = state as u8;
+        }
+        f(&mut Unstructured::new(&data)).unwrap();
+    }
+}
+
+// Operands are generated narrow and widened, so that the operations can't overflow.
+
+fn pos(u: &mut Unstructured) -> Result<Positive<i64>> {
+    let n = Positive::<i32>::arbitrary(u)?.into_inner();
+    Ok(Positive::new(i64::from(n)).unwrap())
+}
+
+fn neg(u: &mut Unstructured) -> Result<Negative<i64>> {
+    let n = Negative::<i32>::arbitrary(u)?.into_inner();
+    Ok(Negative::new(i64::from(n)).unwrap())
+}
+
+fn pos_unsigned(u: &mut Unstructured) -> Result<Positive<u64>> {
+    let n = Positive::<u32>::arbitrary(u)?.into_inner();
+    Ok(Positive::new(u64::from(n)).unwrap())
+}
+
+fn unsigned(u: &mut Unstructured) -> Result<u64> {
+    u32::arbitrary(u).map(u64::from)
+}
+
+// Integer division truncates towards zero, so division is driven with exact
+// rationals instead.
+
+fn pos_ratio(u: &mut Unstructured) -> Result<Positive<Ratio<i64>>> {
+    let n = Positive::<i32>::arbitrary(u)?.into_inner();
+    Ok(Positive::new(Ratio::from_integer(i64::from(n))).unwrap())
+}
+
+fn neg_ratio(u: &mut Unstructured) -> Result<Negative<Ratio<i64>>> {
+    let n = Negative::<i32>::arbitrary(u)?.into_inner();
+    Ok(Negative::new(Ratio::from_integer(i64::from(n))).unwrap())
+}
+
+fn pos_unsigned_ratio(u: &mut Unstructured) -> Result<Positive<Ratio<u64>>> {
+    let n = Positive::<u32>::arbitrary(u)?.into_inner();
+    Ok(Positive::new(Ratio::from_integer(u64::from(n))).unwrap())
+}
+
+#[test]
+fn arbitrary_in_range() {
+    fuzz(|u| {
+        assert!(Positive::new(Positive::<u8>::arbitrary(u)?.into_inner()).is_ok());
+        assert!(Positive::new(Positive::<i64>::arbitrary(u)?.into_inner()).is_ok());
+        assert!(Negative::new(Negative::<i64>::arbitrary(u)?.into_inner()).is_ok());
+        assert!(Positive::new(Positive::<f64>::arbitrary(u)?.into_inner()).is_ok());
+        assert!(Negative::new(Negative::<f32>::arbitrary(u)?.into_inner()).is_ok());
+        Ok(())
+    })
+}
+
+#[test]
+fn arbitrary_exhausted() {
+    let u = &mut Unstructured::new(&[]);
+    assert_eq!(Positive::<u8>::arbitrary(u).unwrap(), 1);
+    assert_eq!(Negative::<i8>::arbitrary(u).unwrap(), i8::MIN);
+    assert!(Positive::new(Positive::<f64>::arbitrary(u).unwrap().into_inner()).is_ok());
+}
+
+#[test]
+fn derive() {
+    #[derive(Debug, Arbitrary)]
+    struct Config {
+        workers: Positive<u32>,
+        offset: Negative<i16>,
+        scale: Positive<f64>,
+    }
+    fuzz(|u| {
+        let Config {
+            workers,
+            offset,
+            scale,
+        } = Config::arbitrary(u)?;
+        assert!(Positive::new(workers.into_inner()).is_ok());
+        assert!(Negative::new(offset.into_inner()).is_ok());
+        assert!(Positive::new(scale.into_inner()).is_ok());
+        Ok(())
+    })
+}
+
+#[test]
+fn add() {
+    fuzz(|u| {
+        let (lhs, rhs) = (pos(u)?, pos(u)?);
+        assert!(Positive::new((lhs + rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n += rhs;
+        assert!(Positive::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (neg(u)?, neg(u)?);
+        assert!(Negative::new((lhs + rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n += rhs;
+        assert!(Negative::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (pos(u)?, neg(u)?);
+        assert_eq!(lhs + rhs, lhs.into_inner() + rhs.into_inner());
+
+        let (lhs, rhs) = (neg(u)?, pos(u)?);
+        assert_eq!(lhs + rhs, lhs.into_inner() + rhs.into_inner());
+
+        let (lhs, rhs) = (pos_unsigned(u)?, unsigned(u)?);
+        assert!(Positive::new((lhs + rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n += rhs;
+        assert!(Positive::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (neg(u)?, unsigned(u)?);
+        assert_eq!(lhs + rhs, lhs.into_inner() + i64::try_from(rhs).unwrap());
+        Ok(())
+    })
+}
+
+#[test]
+fn sub() {
+    fuzz(|u| {
+        let (lhs, rhs) = (pos(u)?, pos(u)?);
+        assert_eq!(lhs - rhs, lhs.into_inner() - rhs.into_inner());
+
+        let (lhs, rhs) = (neg(u)?, neg(u)?);
+        assert_eq!(lhs - rhs, lhs.into_inner() - rhs.into_inner());
+
+        let (lhs, rhs) = (pos(u)?, neg(u)?);
+        assert!(Positive::new((lhs - rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n -= rhs;
+        assert!(Positive::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (neg(u)?, pos(u)?);
+        assert!(Negative::new((lhs - rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n -= rhs;
+        assert!(Negative::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (pos_unsigned(u)?, unsigned(u)?);
+        let rhs = rhs.min(*lhs.inner());
+        assert_eq!(lhs - rhs, lhs.into_inner() - rhs);
+
+        let (lhs, rhs) = (neg(u)?, unsigned(u)?);
+        assert!(Negative::new((lhs - rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n -= rhs;
+        assert!(Negative::new(n.into_inner()).is_ok());
+        Ok(())
+    })
+}
+
+#[test]
+fn mul() {
+    fuzz(|u| {
+        let (lhs, rhs) = (pos(u)?, pos(u)?);
+        assert!(Positive::new((lhs * rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n *= rhs;
+        assert!(Positive::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (neg(u)?, neg(u)?);
+        assert!(Positive::new((lhs * rhs).into_inner()).is_ok());
+
+        let (lhs, rhs) = (pos(u)?, neg(u)?);
+        assert!(Negative::new((lhs * rhs).into_inner()).is_ok());
+
+        let (lhs, rhs) = (neg(u)?, pos(u)?);
+        assert!(Negative::new((lhs * rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n *= rhs;
+        assert!(Negative::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (pos_unsigned(u)?, unsigned(u)?);
+        assert_eq!(lhs * rhs, lhs.into_inner() * rhs);
+
+        let (lhs, rhs) = (neg(u)?, unsigned(u)?);
+        assert_eq!(lhs * rhs, lhs.into_inner() * i64::try_from(rhs).unwrap());
+        Ok(())
+    })
+}
+
+#[test]
+fn div() {
+    fuzz(|u| {
+        let (lhs, rhs) = (pos_ratio(u)?, pos_ratio(u)?);
+        assert!(Positive::new((lhs / rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n /= rhs;
+        assert!(Positive::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (neg_ratio(u)?, neg_ratio(u)?);
+        assert!(Positive::new((lhs / rhs).into_inner()).is_ok());
+
+        let (lhs, rhs) = (pos_ratio(u)?, neg_ratio(u)?);
+        assert!(Negative::new((lhs / rhs).into_inner()).is_ok());
+
+        let (lhs, rhs) = (neg_ratio(u)?, pos_ratio(u)?);
+        assert!(Negative::new((lhs / rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n /= rhs;
+        assert!(Negative::new(n.into_inner()).is_ok());
+
+        let (lhs, rhs) = (pos_unsigned_ratio(u)?, pos_unsigned(u)?.into_inner());
+        assert!(Positive::new((lhs / rhs).into_inner()).is_ok());
+        let mut n = lhs;
+        n /= rhs;
+        assert!(Positive::new(n.into_inner()).is_ok());
+
+        // Truncates, so it's the inner type.
+        let (lhs, rhs) = (neg(u)?, pos_unsigned(u)?.into_inner());
+        assert_eq!(lhs / rhs, lhs.into_inner() / i64::try_from(rhs).unwrap());
+        Ok(())
+    })
+}
+
+#[test]
+fn negation() {
+    fuzz(|u| {
+        assert!(Negative::new((-pos(u)?).into_inner()).is_ok());
+        assert!(Positive::new((-neg(u)?).into_inner()).is_ok());
+        Ok(())
+    })
+}
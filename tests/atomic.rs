@@ -0,0 +1,84 @@
+use std::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release, SeqCst};
+
+use num::One as _;
+use numwit::{AtomicPositiveU32, AtomicPositiveU64, AtomicPositiveUsize, Positive};
+
+fn pos<T: num::Zero + PartialOrd + std::fmt::Debug>(n: T) -> Positive<T> {
+    Positive::new(n).unwrap()
+}
+
+#[test]
+fn load_store() {
+    let n = AtomicPositiveU32::new(pos(1));
+    assert_eq!(n.load(Relaxed), 1);
+    n.store(pos(5), Release);
+    assert_eq!(n.load(Acquire), 5);
+    assert_eq!(n.swap(pos(2), SeqCst), 5);
+    assert_eq!(format!("{n:?}"), "Positive(2)");
+}
+
+#[test]
+fn fetch_add() {
+    let n = AtomicPositiveU64::new(pos(1));
+    assert_eq!(n.fetch_add(pos(2), AcqRel), Some(pos(1)));
+    assert_eq!(n.load(Relaxed), 3);
+    n.store(pos(u64::MAX - 1), Relaxed);
+    assert_eq!(n.fetch_add(pos(2), Release), None);
+    assert_eq!(n.load(Relaxed), u64::MAX - 1);
+    assert_eq!(
+        n.fetch_add(Positive::one(), SeqCst),
+        Some(pos(u64::MAX - 1))
+    );
+    assert_eq!(n.into_inner(), u64::MAX);
+}
+
+#[test]
+fn fetch_max_min() {
+    let n = AtomicPositiveUsize::from(pos(5));
+    assert_eq!(n.fetch_max(pos(7), Relaxed), 5);
+    assert_eq!(n.fetch_min(pos(2), Relaxed), 7);
+    assert_eq!(n.load(Relaxed), 2);
+}
+
+#[test]
+fn compare_exchange() {
+    let n = AtomicPositiveU32::new(pos(1));
+    assert_eq!(
+        n.compare_exchange(pos(1), pos(2), AcqRel, Acquire),
+        Ok(pos(1))
+    );
+    assert_eq!(
+        n.compare_exchange(pos(1), pos(3), AcqRel, Acquire),
+        Err(pos(2))
+    );
+    let mut current = n.load(Relaxed);
+    loop {
+        match n.compare_exchange_weak(current, current + current, AcqRel, Acquire) {
+            Ok(_) => break,
+            Err(it) => current = it,
+        }
+    }
+    assert_eq!(n.load(Relaxed), 4);
+}
+
+#[test]
+fn get_mut() {
+    let mut n = AtomicPositiveU32::new(pos(1));
+    *n.get_mut() += pos(1);
+    assert_eq!(n.load(Relaxed), 2);
+}
+
+#[test]
+fn threads() {
+    let n = AtomicPositiveUsize::new(Positive::one());
+    std::thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    n.fetch_add(Positive::one(), Relaxed).unwrap();
+                }
+            });
+        }
+    });
+    assert_eq!(n.into_inner(), 8001);
+}
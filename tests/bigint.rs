@@ -0,0 +1,98 @@
+use num::{BigInt, BigRational, BigUint, One as _};
+use numwit::{Negative, Positive};
+
+fn big(n: i64) -> BigInt {
+    BigInt::from(n)
+}
+
+fn ubig(n: u64) -> BigUint {
+    BigUint::from(n)
+}
+
+#[test]
+fn new() {
+    assert!(Positive::new(big(1)).is_ok());
+    assert!(Positive::new(big(0)).is_err());
+    assert!(Negative::new(big(-1)).is_ok());
+    assert!(Positive::new(ubig(1)).is_ok());
+    assert!(Positive::new(ubig(0)).is_err());
+    assert!(Positive::new(BigRational::new(big(1), big(2))).is_ok());
+    assert!(Negative::new(BigRational::new(big(-1), big(2))).is_ok());
+    assert!(Negative::new(BigRational::new(big(1), big(2))).is_err());
+}
+
+#[test]
+fn serde() {
+    let n = Positive::new(big(1) << 100).unwrap();
+    let json = serde_json::to_string(&n).unwrap();
+    assert_eq!(serde_json::from_str::<Positive<BigInt>>(&json).unwrap(), n);
+    assert!(
+        serde_json::from_str::<Positive<BigInt>>(&serde_json::to_string(&big(-1)).unwrap())
+            .is_err()
+    );
+
+    let r = Negative::new(BigRational::new(big(-1), big(3))).unwrap();
+    let json = serde_json::to_string(&r).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Negative<BigRational>>(&json).unwrap(),
+        r
+    );
+}
+
+#[test]
+fn refs() {
+    let a = Positive::new(big(2)).unwrap();
+    let b = Positive::new(big(3)).unwrap();
+    let c: Positive<BigInt> = &a + &b;
+    assert_eq!(c, big(5));
+    let c: Positive<BigInt> = a.clone() * &b;
+    assert_eq!(c, big(6));
+    assert_eq!(&a - &b, big(-1));
+    let n: Negative<BigInt> = -&a;
+    assert_eq!(n, big(-2));
+    let d: Negative<BigInt> = &n - &b;
+    assert_eq!(d, big(-5));
+    let mut e = a.clone();
+    e += b;
+    assert_eq!(e, big(5));
+}
+
+#[test]
+fn rational() {
+    let half = Positive::new(BigRational::new(big(1), big(2))).unwrap();
+    let third = Positive::new(BigRational::new(big(1), big(3))).unwrap();
+    let sum: Positive<BigRational> = &half + &third;
+    assert_eq!(sum, BigRational::new(big(5), big(6)));
+    let quotient: Positive<BigRational> = &half / &third;
+    assert_eq!(quotient, BigRational::new(big(3), big(2)));
+}
+
+#[test]
+fn unsigned() {
+    let n: Negative<BigInt> = Negative::new(big(-1)).unwrap() - 2_u8;
+    assert_eq!(n, big(-3));
+
+    let n = Positive::<BigUint>::one();
+    let sum: Positive<BigUint> = &n + &ubig(1);
+    assert_eq!(sum, ubig(2));
+    let sum: Positive<BigUint> = n.clone() + &ubig(2);
+    assert_eq!(sum, ubig(3));
+    let sum: Positive<BigUint> = n.clone() + ubig(3);
+    assert_eq!(sum, ubig(4));
+    let quotient: Positive<BigUint> = sum / ubig(2);
+    assert_eq!(quotient, ubig(2));
+    let mut m = n;
+    m += ubig(1);
+    m *= Positive::new(ubig(3)).unwrap();
+    assert_eq!(m, ubig(6));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn primitive_refs() {
+    let n = Positive::new(1u8).unwrap();
+    assert_eq!(&n + &1u8, 2);
+    assert_eq!(n + &2u8, 3);
+    assert_eq!(&n + &n, 2);
+    assert_eq!(n * &n, 1);
+}
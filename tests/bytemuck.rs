@@ -0,0 +1,31 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::checked::{self, CheckedCastError};
+use numwit::{Negative, Positive};
+
+#[test]
+fn cast_slice() {
+    let buf = [1_u32, 2, 3];
+    let cast: &[Positive<u32>] = checked::try_cast_slice(&buf).unwrap();
+    assert_eq!(cast, Positive::slice_from(&buf).unwrap());
+    assert_eq!(bytemuck::cast_slice::<_, u32>(cast), buf);
+
+    assert_eq!(
+        checked::try_cast_slice::<u32, Positive<u32>>(&[1, 0, 3]),
+        Err(CheckedCastError::InvalidBitPattern)
+    );
+}
+
+#[test]
+fn cast_bytes() {
+    let bytes = (-2_i16).to_ne_bytes();
+    assert_eq!(
+        checked::try_from_bytes::<Negative<i16>>(&bytes).unwrap(),
+        &-2
+    );
+    let bytes = 2_i16.to_ne_bytes();
+    assert_eq!(
+        checked::try_from_bytes::<Negative<i16>>(&bytes),
+        Err(CheckedCastError::InvalidBitPattern)
+    );
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "clap")]
+
+use clap::{error::ErrorKind, Parser};
+use numwit::{Negative, Positive};
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[arg(long)]
+    workers: Positive<usize>,
+    #[arg(long)]
+    offset: Option<Negative<i32>>,
+    #[arg(long, default_value = "1.0")]
+    scale: Positive<f64>,
+}
+
+#[test]
+fn parse() {
+    let args = Args::try_parse_from(["prog", "--workers", "4", "--offset=-3"]).unwrap();
+    assert_eq!(args.workers, 4);
+    assert_eq!(args.offset.unwrap(), -3);
+    assert_eq!(args.scale, 1.0);
+}
+
+#[test]
+fn wrong_sign() {
+    let e = Args::try_parse_from(["prog", "--workers", "0"]).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::ValueValidation);
+    let rendered = e.render().to_string();
+    assert!(rendered.contains("invalid value '0' for '--workers <WORKERS>'"));
+    assert!(rendered.contains("the value must be positive (greater than 0)"));
+    assert!(rendered.contains("the nearest valid value is '1'"));
+
+    let e = Args::try_parse_from(["prog", "--workers", "1", "--offset", "5"]).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::ValueValidation);
+    let rendered = e.render().to_string();
+    assert!(rendered.contains("invalid value '5' for '--offset <OFFSET>'"));
+    assert!(rendered.contains("the value must be negative (less than 0)"));
+    assert!(rendered.contains("the nearest valid value is '-1'"));
+
+    let e = Args::try_parse_from(["prog", "--workers", "1", "--scale", "0"]).unwrap_err();
+    let rendered = e.render().to_string();
+    assert!(rendered.contains("the value must be positive (greater than 0)"));
+    assert!(!rendered.contains("nearest"));
+}
+
+#[test]
+fn not_a_number() {
+    let e = Args::try_parse_from(["prog", "--workers", "many"]).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::ValueValidation);
+    assert!(e
+        .render()
+        .to_string()
+        .contains("invalid value 'many' for '--workers <WORKERS>': invalid digit found in string"));
+}
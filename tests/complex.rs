@@ -0,0 +1,48 @@
+use std::f64::consts::PI;
+
+use num::Complex;
+use numwit::{IsNonZero, NonZeroComplex, Positive, Witness};
+
+#[test]
+fn new() {
+    assert!(NonZeroComplex::new(Complex::new(0.0, 1.0)).is_ok());
+    assert!(NonZeroComplex::new(Complex::new(-1.0, 0.0)).is_ok());
+    assert!(NonZeroComplex::new(Complex::new(0.0, 0.0)).is_err());
+    assert!(NonZeroComplex::new(Complex::new(f64::NAN, 1.0)).is_err());
+    assert_eq!(
+        NonZeroComplex::new(Complex::new(0.0, 0.0))
+            .unwrap_err()
+            .to_string(),
+        "The value 0+0i was not non-zero"
+    );
+}
+
+#[test]
+fn norm() {
+    let z = NonZeroComplex::new(Complex::new(3.0, 4.0)).unwrap();
+    assert_eq!(z.norm(), 5.0);
+    assert_eq!(z.norm_sqr().unwrap(), 25.0);
+
+    let tiny = NonZeroComplex::new(Complex::new(1e-200, 0.0)).unwrap();
+    assert_eq!(tiny.norm(), 1e-200);
+    assert_eq!(tiny.norm_sqr(), None);
+}
+
+#[test]
+fn polar() {
+    let norm = Positive::new(2.0).unwrap();
+    let z = NonZeroComplex::from_polar(norm, PI / 2.0).unwrap();
+    assert!((z.inner().im - 2.0).abs() < 1e-12);
+    let (r, theta) = z.to_polar();
+    assert!((r.into_inner() - 2.0).abs() < 1e-12);
+    assert!((theta - PI / 2.0).abs() < 1e-12);
+
+    assert!(NonZeroComplex::from_polar(norm, f64::INFINITY).is_err());
+}
+
+#[test]
+fn implied() {
+    let n = Positive::new(2).unwrap();
+    let _: &Witness<i32, IsNonZero> = n.project();
+    assert!(Witness::<f64, IsNonZero>::new(f64::NAN).is_err());
+}
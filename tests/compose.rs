@@ -0,0 +1,92 @@
+use numwit::{
+    And, GreaterThan, IsNegative, IsPositive, LessThan, Negative, Not, Or, Positive, Witness,
+};
+
+type Small = Witness<u32, And<IsPositive, LessThan<1000>>>;
+
+#[test]
+fn and() {
+    let n = Small::new(10).unwrap();
+    assert_eq!(n, 10);
+    assert_eq!(format!("{n:?}"), "And<Positive, LessThan<1000>>(10)");
+    assert_eq!(
+        Small::new(0).unwrap_err().to_string(),
+        "The value 0 was not positive"
+    );
+    assert_eq!(
+        Small::new(1000).unwrap_err().to_string(),
+        "The value 1000 was not less than 1000"
+    );
+    assert_eq!(
+        Small::slice_from(&[1, 2, 1000]).unwrap_err().1.to_string(),
+        "The value 1000 was not less than 1000"
+    );
+}
+
+#[test]
+fn and_from_witness() {
+    let n: Small = Positive::new(10_u32).unwrap().and().unwrap();
+    assert_eq!(n, 10);
+    let e = Positive::new(5000_u32)
+        .unwrap()
+        .and::<LessThan<1000>>()
+        .unwrap_err();
+    assert_eq!(e.0, 5000);
+}
+
+#[test]
+fn projection() {
+    let n = Small::new(10).unwrap();
+    assert_eq!(*n.as_positive() + *n.as_positive(), 20);
+    let less: &Witness<u32, LessThan<1000>> = n.project();
+    assert_eq!(*less, 10);
+
+    let nested =
+        Witness::<i64, And<GreaterThan<-100>, And<IsNegative, Not<LessThan<-50>>>>>::new(-10)
+            .unwrap();
+    let neg: Negative<i64> = nested.weaken();
+    assert_eq!(-neg, 10);
+    assert_eq!(*nested.as_negative(), -10);
+}
+
+#[test]
+fn weakening() {
+    let pos = Small::new(10).unwrap().weaken::<IsPositive, _>();
+    assert_eq!(pos + pos, 20);
+
+    let n: Witness<i32, Or<IsNegative, IsPositive>> = Positive::new(1).unwrap().weaken();
+    assert_eq!(n, 1);
+}
+
+#[test]
+fn or() {
+    type NonZero = Witness<i32, Or<IsPositive, IsNegative>>;
+    assert!(NonZero::new(1).is_ok());
+    assert!(NonZero::new(-1).is_ok());
+    assert_eq!(
+        NonZero::new(0).unwrap_err().to_string(),
+        "The value 0 was not positive or negative"
+    );
+}
+
+#[test]
+fn not() {
+    type NonPositive = Witness<i32, Not<IsPositive>>;
+    assert!(NonPositive::new(0).is_ok());
+    assert_eq!(
+        format!("{:?}", NonPositive::new(-1).unwrap()),
+        "Not<Positive>(-1)"
+    );
+    assert_eq!(
+        NonPositive::new(1).unwrap_err().to_string(),
+        "The value 1 was not non-positive"
+    );
+}
+
+#[test]
+fn bounds() {
+    assert!(Witness::<u128, LessThan<0>>::new(u128::MAX).is_err());
+    assert!(Witness::<u128, GreaterThan<0>>::new(u128::MAX).is_ok());
+    assert!(Witness::<i8, GreaterThan<-128>>::new(i8::MIN).is_err());
+    assert!(Witness::<i8, LessThan<-127>>::new(i8::MIN).is_ok());
+}
@@ -0,0 +1,53 @@
+use numwit::{Negative, Positive};
+
+#[test]
+fn widen() {
+    let n = Positive::new(200u8).unwrap();
+    assert_eq!(Positive::<u32>::from(n), 200);
+    assert_eq!(Positive::<i16>::from(n), 200);
+    assert_eq!(Positive::<usize>::from(n), 200);
+    let n = Positive::new(i64::MAX).unwrap();
+    assert_eq!(Positive::<u64>::from(n), i64::MAX as u64);
+    assert_eq!(Positive::<i128>::from(n), i64::MAX as i128);
+    let n = Positive::new(i8::MAX).unwrap();
+    assert_eq!(Positive::<u8>::from(n), 127);
+
+    let n = Negative::new(i8::MIN).unwrap();
+    assert_eq!(Negative::<i64>::from(n), -128);
+    assert_eq!(Negative::<isize>::from(n), -128);
+}
+
+#[test]
+fn narrow() {
+    let n = Positive::new(255u32).unwrap();
+    assert_eq!(Positive::<u8>::try_from(n).unwrap(), 255);
+    assert!(Positive::<i8>::try_from(n).is_err());
+    let n = Positive::new(256i32).unwrap();
+    assert!(Positive::<u8>::try_from(n).is_err());
+    let n = Positive::new(u128::MAX).unwrap();
+    assert!(Positive::<i128>::try_from(n).is_err());
+    let n = Positive::new(u16::MAX).unwrap();
+    assert_eq!(Positive::<isize>::try_from(n).unwrap(), 65535);
+
+    let n = Negative::new(-128i32).unwrap();
+    assert_eq!(Negative::<i8>::try_from(n).unwrap(), -128);
+    let n = Negative::new(-129i32).unwrap();
+    assert!(Negative::<i8>::try_from(n).is_err());
+}
+
+#[test]
+fn unsigned_abs() {
+    assert_eq!(Positive::new(i8::MAX).unwrap().unsigned_abs(), 127_u8);
+    assert_eq!(Negative::new(i8::MIN).unwrap().unsigned_abs(), 128_u8);
+    assert_eq!(Negative::new(i64::MIN).unwrap().unsigned_abs(), 1 << 63);
+}
+
+#[test]
+fn to_signed() {
+    assert_eq!(
+        Positive::new(127_u8).unwrap().to_signed(),
+        Positive::new(127_i8).ok()
+    );
+    assert_eq!(Positive::new(128_u8).unwrap().to_signed(), None);
+    assert_eq!(Positive::new(usize::MAX).unwrap().to_signed(), None);
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "derive")]
+
+use num::One as _;
+use numwit::{Negative, Positive, WitnessOps};
+
+#[derive(Debug, Clone, Copy, PartialEq, WitnessOps)]
+struct Quantity(Positive<u64>);
+
+#[derive(Debug, Clone, Copy, PartialEq, WitnessOps)]
+struct Debt {
+    amount: Negative<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, WitnessOps)]
+struct Scale<T>(Positive<T>);
+
+fn qty(n: u64) -> Quantity {
+    Quantity(Positive::new(n).unwrap())
+}
+
+fn debt(n: i64) -> Debt {
+    Debt {
+        amount: Negative::new(n).unwrap(),
+    }
+}
+
+#[test]
+fn positive() {
+    assert_eq!(qty(2) + qty(3), qty(5));
+    assert_eq!(qty(2) + 3, qty(5));
+    assert_eq!(qty(2) * qty(3), qty(6));
+    assert_eq!(qty(6) / qty(3), qty(2));
+    assert_eq!(qty(6) / 3, qty(2));
+
+    let mut n = qty(1);
+    n += qty(1);
+    n += 1;
+    n *= qty(3);
+    n /= qty(3);
+    n /= 3;
+    assert_eq!(n, qty(1));
+}
+
+#[test]
+fn negative() {
+    let pos = Positive::new(2_i64).unwrap();
+    assert_eq!(debt(-2) + debt(-3), debt(-5));
+    assert_eq!(debt(-2) - pos, debt(-4));
+    assert_eq!(debt(-2) * pos, debt(-4));
+    assert_eq!(debt(-4) / pos, debt(-2));
+
+    let mut n = debt(-1);
+    n += debt(-1);
+    n -= pos;
+    n *= pos;
+    n /= pos;
+    assert_eq!(n, debt(-4));
+}
+
+#[test]
+fn opposite() {
+    let pos = Scale(Positive::new(2_i64).unwrap());
+    assert_eq!(
+        pos - Negative::new(-1).unwrap(),
+        Scale(Positive::new(3).unwrap())
+    );
+    assert_eq!(
+        Scale(Positive::<f64>::one()) * Scale(Positive::one()),
+        Scale(Positive::one())
+    );
+}
+
+#[test]
+fn deref_and_display() {
+    assert_eq!(*qty(2), Positive::new(2).unwrap());
+    assert_eq!(qty(2).into_inner(), 2);
+    assert_eq!(qty(2).to_string(), "2");
+    assert_eq!(debt(-2).to_string(), "-2");
+}
+
+#[test]
+fn serde() {
+    assert_eq!(serde_json::to_string(&qty(2)).unwrap(), "2");
+    assert_eq!(serde_json::from_str::<Quantity>("2").unwrap(), qty(2));
+    assert!(serde_json::from_str::<Quantity>("0").is_err());
+    assert_eq!(serde_json::from_str::<Debt>("-2").unwrap(), debt(-2));
+}
+
+/// Only the field's alias is in scope, not the opposite one.
+mod unimported {
+    use numwit::{Positive, WitnessOps};
+
+    #[derive(Debug, Clone, Copy, PartialEq, WitnessOps)]
+    pub struct Quantity(Positive<i64>);
+
+    #[derive(Debug, Clone, Copy, PartialEq, WitnessOps)]
+    pub struct Debt(numwit::Negative<i64>);
+
+    #[test]
+    fn opposite() {
+        let pos = Positive::new(2).unwrap();
+        let neg = numwit::Negative::new(-1).unwrap();
+        assert_eq!(Quantity(pos) - neg, Quantity(Positive::new(3).unwrap()));
+        assert_eq!(Debt(neg) * pos, Debt(numwit::Negative::new(-2).unwrap()));
+    }
+}
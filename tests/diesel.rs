@@ -0,0 +1,79 @@
+#![cfg(feature = "diesel")]
+
+use std::error::Error;
+
+use diesel::{
+    result::DeserializeFieldError, sqlite::SqliteConnection, Connection as _,
+    ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _,
+};
+use numwit::{Negative, NotNegative, NotPositive, Positive};
+
+diesel::table! {
+    t (price) {
+        price -> BigInt,
+        delta -> BigInt,
+    }
+}
+
+fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE t (price INTEGER NOT NULL, delta INTEGER NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+    conn
+}
+
+#[test]
+fn round_trip() {
+    let conn = &mut connect();
+    let (price, delta) = (
+        Positive::new(2_i64).unwrap(),
+        Negative::new(-3_i64).unwrap(),
+    );
+    diesel::insert_into(t::table)
+        .values((t::price.eq(price), t::delta.eq(&delta)))
+        .execute(conn)
+        .unwrap();
+    let row = t::table
+        .filter(t::price.eq(&price))
+        .first::<(Positive<i64>, Negative<i64>)>(conn)
+        .unwrap();
+    assert_eq!(row, (price, delta));
+}
+
+fn field_error(e: Box<dyn Error + Send + Sync>) -> Box<dyn Error + Send + Sync> {
+    e.downcast::<DeserializeFieldError>().unwrap().error
+}
+
+#[test]
+fn deserialization_error() {
+    let conn = &mut connect();
+    diesel::insert_into(t::table)
+        .values((t::price.eq(0), t::delta.eq(1)))
+        .execute(conn)
+        .unwrap();
+
+    let diesel::result::Error::DeserializationError(e) = t::table
+        .select(t::price)
+        .first::<Positive<i64>>(conn)
+        .unwrap_err()
+    else {
+        panic!("expected a deserialization error")
+    };
+    assert_eq!(
+        field_error(e).downcast_ref::<NotPositive<i64>>().unwrap().0,
+        0
+    );
+
+    let diesel::result::Error::DeserializationError(e) = t::table
+        .select(t::delta)
+        .first::<Negative<i64>>(conn)
+        .unwrap_err()
+    else {
+        panic!("expected a deserialization error")
+    };
+    assert_eq!(
+        field_error(e).downcast_ref::<NotNegative<i64>>().unwrap().0,
+        1
+    );
+}
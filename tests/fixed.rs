@@ -0,0 +1,55 @@
+#![cfg(feature = "fixed")]
+
+use fixed::types::I16F16;
+use num::{CheckedAdd as _, CheckedDiv as _, CheckedMul as _};
+use numwit::{Negative, Positive};
+
+fn pos(n: f64) -> Positive<I16F16> {
+    Positive::new(I16F16::from_num(n)).unwrap()
+}
+
+fn neg(n: f64) -> Negative<I16F16> {
+    Negative::new(I16F16::from_num(n)).unwrap()
+}
+
+#[test]
+fn new() {
+    assert!(Positive::new(I16F16::ZERO).is_err());
+    assert!(Positive::new(I16F16::DELTA).is_ok());
+    assert!(Negative::new(-I16F16::DELTA).is_ok());
+}
+
+#[test]
+fn ops() {
+    assert_eq!(pos(1.5) + pos(2.25), I16F16::from_num(3.75));
+    assert_eq!(neg(-1.5) + neg(-2.25), I16F16::from_num(-3.75));
+    assert_eq!(pos(1.5) - neg(-2.25), I16F16::from_num(3.75));
+    assert_eq!(neg(-1.5) - pos(2.25), I16F16::from_num(-3.75));
+    assert_eq!(pos(1.5) * pos(2.0), I16F16::from_num(3));
+    let product: Positive<I16F16> = neg(-1.5) * neg(-2.0);
+    assert_eq!(product, I16F16::from_num(3));
+    assert_eq!(pos(3.0) / neg(-2.0), I16F16::from_num(-1.5));
+    assert_eq!(-pos(1.5), I16F16::from_num(-1.5));
+
+    let mut n = pos(1.5);
+    n += &pos(0.5);
+    n /= pos(4.0);
+    assert_eq!(n, I16F16::from_num(0.5));
+}
+
+#[test]
+fn checked() {
+    assert_eq!(pos(1.5).checked_add(&pos(2.25)), Some(pos(3.75)));
+    assert_eq!(pos(1.5).checked_sub(&neg(-2.25)), Some(pos(3.75)));
+    assert_eq!(neg(-1.5).checked_sub(&pos(2.25)), Some(neg(-3.75)));
+    assert_eq!(pos(1.5).checked_div(&pos(2.0)), Some(pos(0.75)));
+
+    let max = Positive::new(I16F16::MAX).unwrap();
+    assert_eq!(max.checked_add(&pos(1.0)), None);
+    assert_eq!(max.checked_mul(&pos(2.0)), None);
+
+    // Truncates to zero.
+    let delta = Positive::new(I16F16::DELTA).unwrap();
+    assert_eq!(delta.checked_mul(&delta), None);
+    assert_eq!(delta.checked_div(&pos(2.0)), None);
+}
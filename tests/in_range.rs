@@ -0,0 +1,89 @@
+use numwit::{InRange, Negative, Positive};
+
+type Percent = InRange<u8, 0, 100>;
+type Port = InRange<u16, 1, 65535>;
+type Retries = InRange<i32, 1, 10>;
+
+#[test]
+fn new() {
+    assert_eq!(Percent::new(0).unwrap(), 0);
+    assert_eq!(Percent::new(100).unwrap(), 100);
+    assert_eq!(Percent::new(101).unwrap_err().0, 101);
+    assert_eq!(Port::new(0).unwrap_err().0, 0);
+    assert_eq!(Port::new(u16::MAX).unwrap(), u16::MAX);
+    assert!(InRange::<u128, 0, { i128::MAX }>::new(u128::MAX).is_err());
+    assert_eq!(
+        Percent::new(101).unwrap_err().to_string(),
+        "The value 101 was not in the range 0..=100"
+    );
+    assert_eq!(
+        format!("{:?}", Percent::new(42).unwrap()),
+        "InRange<0, 100>(42)"
+    );
+}
+
+#[test]
+fn checked() {
+    let n = Retries::new(6).unwrap();
+    assert_eq!(n.checked_add(Retries::new(4).unwrap()).unwrap(), 10);
+    assert!(n.checked_add(n).is_none());
+    assert!(n.checked_sub(n).is_none());
+    assert_eq!(n.checked_sub(Retries::new(5).unwrap()).unwrap(), 1);
+    assert!(n.checked_mul(n).is_none());
+    assert!(InRange::<u8, 0, 255>::new(255)
+        .unwrap()
+        .checked_add(InRange::<u8, 1, 1>::new(1).unwrap())
+        .is_none());
+}
+
+#[test]
+fn widening() {
+    let n = Retries::new(10).unwrap();
+    let sum: InRange<i32, 2, 20> = n.widening_add(n);
+    assert_eq!(sum, 20);
+    let diff: InRange<i32, -9, 9> = n.widening_sub(Retries::new(1).unwrap());
+    assert_eq!(diff, 9);
+    let product: InRange<i32, 1, 100> = n.widening_mul(n);
+    assert_eq!(product, 100);
+
+    let neg = InRange::<i32, -3, 2>::new(-3).unwrap();
+    let product: InRange<i32, -30, 20> = neg.widening_mul(n);
+    assert_eq!(product, -30);
+
+    // bounds wider than `u8` are clamped to what a `u8` can hold
+    let n = InRange::<u8, -100, 15>::new(15).unwrap();
+    let product: InRange<u8, 0, 225> = n.widening_mul(n);
+    assert_eq!(product, 225);
+}
+
+#[test]
+fn positive() {
+    let p: Positive<u16> = Port::new(80).unwrap().positive().unwrap();
+    assert_eq!(p, 80);
+    assert_eq!(Port::try_from(p).unwrap(), 80);
+    assert!(InRange::<u16, 1, 10>::try_from(p).is_err());
+
+    let n: Negative<i8> = InRange::<i8, -10, -1>::new(-5).unwrap().negative().unwrap();
+    assert_eq!(n, -5);
+    assert_eq!(InRange::<i8, -10, -1>::try_from(n).unwrap(), -5);
+
+    let n = InRange::<i8, -3, 2>::new(2).unwrap();
+    assert_eq!(n.positive().unwrap(), 2);
+    assert_eq!(n.negative(), None);
+    assert_eq!(InRange::<i8, -3, 2>::new(0).unwrap().positive(), None);
+
+    let p: Positive<u16> = Port::new(80).unwrap().into_positive();
+    assert_eq!(p, 80);
+    let n: Negative<i8> = InRange::<i8, -10, -1>::new(-5).unwrap().into_negative();
+    assert_eq!(n, -5);
+}
+
+#[test]
+fn serde() {
+    assert_eq!(serde_json::from_str::<Percent>("42").unwrap(), 42);
+    assert!(serde_json::from_str::<Percent>("142").is_err());
+    assert_eq!(
+        serde_json::to_string(&Percent::new(42).unwrap()).unwrap(),
+        "42"
+    );
+}
@@ -0,0 +1,110 @@
+use numwit::{Interval, IntervalSign, Negative, Positive};
+
+fn pos(lo: f64, hi: f64) -> Interval<Positive<f64>> {
+    Interval::positive(Positive::new(lo).unwrap(), Positive::new(hi).unwrap()).unwrap()
+}
+
+fn neg(lo: f64, hi: f64) -> Interval<Negative<f64>> {
+    Interval::negative(Negative::new(lo).unwrap(), Negative::new(hi).unwrap()).unwrap()
+}
+
+fn interval(lo: f64, hi: f64) -> Interval<f64> {
+    Interval::new(lo, hi).unwrap()
+}
+
+#[test]
+fn new() {
+    assert!(Interval::new(2, 1).is_none());
+    assert!(Interval::new(f64::NAN, 1.0).is_none());
+    assert!(Interval::positive(Positive::new(2).unwrap(), Positive::new(1).unwrap()).is_none());
+    assert_eq!(Interval::point(1).into_inner(), (1, 1));
+    assert_eq!(interval(1.0, 2.0).to_string(), "[1, 2]");
+}
+
+#[test]
+fn arithmetic() {
+    let a = pos(1.0, 2.0);
+    let b = pos(4.0, 8.0);
+
+    let sum: Interval<Positive<f64>> = a + b;
+    assert_eq!(Interval::from(sum), interval(5.0, 10.0));
+    let product: Interval<Positive<f64>> = a * b;
+    assert_eq!(Interval::from(product), interval(4.0, 16.0));
+    let quotient: Interval<Positive<f64>> = b / a;
+    assert_eq!(Interval::from(quotient), interval(2.0, 8.0));
+    let difference: Interval<f64> = a - b;
+    assert_eq!(difference, interval(-7.0, -2.0));
+    let negated: Interval<Negative<f64>> = -a;
+    assert_eq!(Interval::from(negated), interval(-2.0, -1.0));
+
+    let c = neg(-3.0, -1.0);
+    let difference: Interval<Positive<f64>> = a - c;
+    assert_eq!(Interval::from(difference), interval(2.0, 5.0));
+    let product: Interval<Negative<f64>> = a * c;
+    assert_eq!(Interval::from(product), interval(-6.0, -1.0));
+    let quotient: Interval<Positive<f64>> = neg(-4.0, -2.0) / neg(-2.0, -1.0);
+    assert_eq!(Interval::from(quotient), interval(1.0, 4.0));
+    let product: Interval<f64> = interval(-1.0, 2.0) * Interval::<f64>::from(c);
+    assert_eq!(product, interval(-6.0, 3.0));
+    let product = interval(-1.0, 2.0) * interval(-3.0, 1.0);
+    assert_eq!(product, interval(-6.0, 3.0));
+}
+
+#[test]
+fn div_unwitnessed() {
+    let IntervalSign::ContainsZero(a) = interval(-1.0, 2.0).classify() else {
+        panic!("should contain zero")
+    };
+    assert_eq!(a.div_positive(pos(1.0, 2.0)), interval(-1.0, 2.0));
+    assert_eq!(a.div_negative(neg(-2.0, -1.0)), interval(-2.0, 1.0));
+}
+
+#[test]
+fn classify() {
+    assert_eq!(
+        interval(1.0, 2.0).classify(),
+        IntervalSign::Positive(pos(1.0, 2.0))
+    );
+    assert_eq!(
+        interval(-2.0, -1.0).classify(),
+        IntervalSign::Negative(neg(-2.0, -1.0))
+    );
+    assert_eq!(
+        interval(0.0, 1.0).classify(),
+        IntervalSign::ContainsZero(interval(0.0, 1.0))
+    );
+    assert_eq!(
+        interval(-1.0, 0.0).classify(),
+        IntervalSign::ContainsZero(interval(-1.0, 0.0))
+    );
+}
+
+#[test]
+fn sets() {
+    let a = interval(1.0, 3.0);
+    let b = interval(2.0, 4.0);
+    assert!(a.contains(&1.0));
+    assert!(a.contains(&3.0));
+    assert!(!a.contains(&3.5));
+    assert_eq!(a.hull(b), interval(1.0, 4.0));
+    assert_eq!(a.intersect(b), Some(interval(2.0, 3.0)));
+    assert_eq!(a.intersect(interval(5.0, 6.0)), None);
+    assert_eq!(pos(1.0, 3.0).intersect(pos(2.0, 4.0)), Some(pos(2.0, 3.0)));
+}
+
+#[test]
+fn round_outward() {
+    let a = interval(1.0, 2.0).round_outward();
+    assert!(*a.lo() < 1.0 && *a.hi() > 2.0);
+
+    let tiny = Positive::new(f64::from_bits(1)).unwrap();
+    let a = Interval::positive(tiny, Positive::new(1.0).unwrap())
+        .unwrap()
+        .round_outward();
+    assert_eq!(*a.lo(), tiny);
+    assert!(*a.hi().inner() > 1.0);
+
+    let a = neg(-2.0, -f64::from_bits(1)).round_outward();
+    assert!(*a.lo().inner() < -2.0);
+    assert_eq!(*a.hi().inner(), -f64::from_bits(1));
+}
@@ -0,0 +1,94 @@
+use num::traits::Pow as _;
+use numwit::{Even, Negative, Odd, Positive};
+
+fn even(n: i32) -> Even<i32> {
+    Even::new(n).unwrap()
+}
+
+fn odd(n: i32) -> Odd<i32> {
+    Odd::new(n).unwrap()
+}
+
+#[test]
+fn new() {
+    assert!(Even::new(0_u8).is_ok());
+    assert!(Even::new(-2_i8).is_ok());
+    assert_eq!(
+        Even::new(3_u8).unwrap_err().to_string(),
+        "The value 3 was not even"
+    );
+    assert!(Odd::new(-3_i8).is_ok());
+    assert_eq!(
+        Odd::new(0_u8).unwrap_err().to_string(),
+        "The value 0 was not odd"
+    );
+    assert_eq!(format!("{:?}", odd(1)), "Odd(1)");
+}
+
+#[test]
+fn add_sub() {
+    let _: Even<i32> = even(2) + even(4);
+    let _: Even<i32> = odd(1) + odd(3);
+    let _: Odd<i32> = even(2) + odd(3);
+    let _: Odd<i32> = odd(3) + even(2);
+    let _: Even<i32> = even(2) - even(4);
+    let _: Even<i32> = odd(1) - odd(3);
+    let _: Odd<i32> = even(2) - odd(3);
+    let _: Odd<i32> = odd(3) - even(2);
+    assert_eq!(odd(1) + odd(3), 4);
+    assert_eq!(even(2) - odd(3), -1);
+
+    let mut n = even(0);
+    n += even(2);
+    n -= even(6);
+    assert_eq!(n, -4);
+    let mut n = odd(1);
+    n += even(2);
+    n -= even(6);
+    assert_eq!(n, -3);
+}
+
+#[test]
+fn mul() {
+    let _: Even<i32> = even(2) * even(4);
+    let _: Odd<i32> = odd(3) * odd(5);
+    let _: Even<i32> = even(2) * odd(3);
+    let _: Even<i32> = odd(3) * even(2);
+    assert_eq!(odd(3) * odd(5), 15);
+
+    let mut n = even(2);
+    n *= even(2);
+    n *= odd(3);
+    assert_eq!(n, 12);
+    let mut n = odd(3);
+    n *= odd(-1);
+    assert_eq!(n, -3);
+}
+
+#[test]
+fn neg() {
+    let _: Even<i32> = -even(2);
+    let _: Odd<i32> = -odd(3);
+    assert_eq!(-odd(3), -3);
+}
+
+#[test]
+fn pow() {
+    let neg = Negative::new(-3_i64).unwrap();
+    let _: Positive<i64> = neg.pow(Even::new(0_u32).unwrap());
+    let _: Positive<i64> = neg.pow(Even::new(2_u32).unwrap());
+    let _: Negative<i64> = neg.pow(Odd::new(3_u32).unwrap());
+    assert_eq!(neg.pow(Even::new(2_u32).unwrap()), 9);
+    assert_eq!(neg.pow(Odd::new(3_u32).unwrap()), -27);
+
+    let pos = Positive::new(2_u64).unwrap();
+    assert_eq!(pos.pow(10_u32), 1024);
+    assert_eq!(pos.pow(Even::new(2_u32).unwrap()), 4);
+    assert_eq!(pos.pow(Odd::new(3_u32).unwrap()), 8);
+
+    let big = Negative::new(num::BigInt::from(-2)).unwrap();
+    assert_eq!(
+        big.pow(Odd::new(65_u32).unwrap()),
+        -num::BigInt::from(2).pow(65_u32)
+    );
+}
@@ -0,0 +1,70 @@
+use numwit::{Positive, PowerOfTwo};
+
+fn p2(n: usize) -> PowerOfTwo<usize> {
+    PowerOfTwo::new(n).unwrap()
+}
+
+#[test]
+fn new() {
+    assert!(PowerOfTwo::new(1_u8).is_ok());
+    assert!(PowerOfTwo::new(128_u8).is_ok());
+    assert!(PowerOfTwo::new(64_i8).is_ok());
+    assert!(PowerOfTwo::new(i8::MIN).is_err());
+    assert!(PowerOfTwo::new(0_u8).is_err());
+    assert_eq!(
+        PowerOfTwo::new(6_u8).unwrap_err().to_string(),
+        "The value 6 was not a power of two"
+    );
+}
+
+#[test]
+fn positive() {
+    let pos: Positive<usize> = p2(8).into();
+    assert_eq!(pos, 8);
+    assert_eq!(*p2(8).as_positive() + *p2(8).as_positive(), 16);
+}
+
+#[test]
+fn helpers() {
+    assert_eq!(p2(1).log2(), 0);
+    assert_eq!(p2(64).log2(), 6);
+    assert_eq!(p2(64).mask(), 0b11_1111);
+    assert_eq!(p2(8).align_down(13), 8);
+    assert_eq!(p2(8).align_down(16), 16);
+    assert_eq!(p2(8).align_up(13), Some(16));
+    assert_eq!(p2(8).align_up(16), Some(16));
+    assert_eq!(p2(8).align_up(usize::MAX), None);
+    assert!(p2(8).is_aligned(24));
+    assert!(!p2(8).is_aligned(25));
+    assert_eq!(PowerOfTwo::new(4_i32).unwrap().align_down(-5), -8);
+}
+
+#[test]
+fn div_rem() {
+    for n in [0_u32, 1, 7, 8, 9, 1000, u32::MAX] {
+        let d = PowerOfTwo::new(8_u32).unwrap();
+        assert_eq!(n / d, n / 8);
+        assert_eq!(n % d, n % 8);
+        let (mut q, mut r) = (n, n);
+        q /= d;
+        r %= d;
+        assert_eq!((q, r), (n / 8, n % 8));
+    }
+}
+
+#[test]
+fn next_power_of_two() {
+    assert_eq!(Positive::new(1_u8).unwrap().next_power_of_two(), 1);
+    assert_eq!(Positive::new(5_u8).unwrap().next_power_of_two(), 8);
+    assert_eq!(Positive::new(128_u8).unwrap().next_power_of_two(), 128);
+    assert!(Positive::new(129_u8)
+        .unwrap()
+        .checked_next_power_of_two()
+        .is_none());
+}
+
+#[test]
+#[should_panic = "next power of two overflowed"]
+fn next_power_of_two_overflow() {
+    Positive::new(129_u8).unwrap().next_power_of_two();
+}
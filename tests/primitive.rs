@@ -0,0 +1,37 @@
+use numwit::{Negative, Positive};
+
+#[test]
+fn unsigned_lhs() {
+    let sum: Positive<u8> = 1_u8 + Positive::new(1_u8).unwrap();
+    assert_eq!(sum, 2);
+    let sum: Positive<i64> = 1_u64 + Positive::new(1_i64).unwrap();
+    assert_eq!(sum, 2);
+    assert_eq!(1_u8 + Negative::new(-3_i8).unwrap(), -2);
+    assert_eq!(0_u8 * Positive::new(2_u8).unwrap(), 0);
+    assert_eq!(2_u16 * Negative::new(-3_i16).unwrap(), -6);
+}
+
+#[test]
+fn same_as_rhs() {
+    let n = Positive::new(5_u32).unwrap();
+    assert_eq!(3_u32 + n, n + 3_u32);
+    assert_eq!(3_u32 * n, n * 3_u32);
+    let n = Negative::new(-5_i32).unwrap();
+    assert_eq!(3_u32 + n, n + 3_u32);
+    assert_eq!(3_u32 * n, n * 3_u32);
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn float() {
+    let n = Positive::new(1.5_f64).unwrap();
+    assert_eq!(2.0 * n, 3.0);
+    assert_eq!(n * 2.0, 3.0);
+    assert_eq!(n * -2.0, -3.0);
+    assert_eq!(&n * &-2.0, -3.0);
+    assert_eq!(-2.0 + n, -0.5);
+    assert_eq!(n + -2.0, -0.5);
+    let n = Negative::new(-1.5_f32).unwrap();
+    assert_eq!(2.0 * n, -3.0);
+    assert_eq!(2.0 + n, n + 2.0);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "proptest")]
+
+use numwit::{Negative, Positive};
+use proptest::{
+    arbitrary::any,
+    strategy::{Strategy, ValueTree as _},
+    test_runner::TestRunner,
+};
+
+proptest::proptest! {
+    #[test]
+    fn positive_u8(n in any::<Positive<u8>>()) {
+        proptest::prop_assert!(n.into_inner() > 0);
+    }
+    #[test]
+    fn positive_i64(n in any::<Positive<i64>>()) {
+        proptest::prop_assert!(n.into_inner() > 0);
+    }
+    #[test]
+    fn negative_i64(n in any::<Negative<i64>>()) {
+        proptest::prop_assert!(n.into_inner() < 0);
+    }
+    #[test]
+    fn positive_f64(n in any::<Positive<f64>>()) {
+        proptest::prop_assert!(n.into_inner() > 0.0);
+    }
+    #[test]
+    fn negative_f64(n in any::<Negative<f64>>()) {
+        proptest::prop_assert!(n.into_inner() < 0.0);
+    }
+}
+
+/// Shrink as far as possible, checking that every intermediate value is valid.
+fn minimal<S: Strategy>(
+    runner: &mut TestRunner,
+    strategy: S,
+    valid: impl Fn(&S::Value) -> bool,
+) -> S::Value {
+    let mut tree = strategy.new_tree(runner).unwrap();
+    while tree.simplify() {
+        assert!(valid(&tree.current()));
+    }
+    tree.current()
+}
+
+#[test]
+fn shrink_towards_one() {
+    let runner = &mut TestRunner::deterministic();
+    for _ in 0..64 {
+        assert_eq!(
+            minimal(runner, any::<Positive<u32>>(), |it| *it.inner() > 0),
+            1
+        );
+        assert_eq!(
+            minimal(runner, any::<Positive<i32>>(), |it| *it.inner() > 0),
+            1
+        );
+        assert_eq!(
+            minimal(runner, any::<Negative<i32>>(), |it| *it.inner() < 0),
+            -1
+        );
+        assert_eq!(
+            minimal(runner, any::<Positive<f64>>(), |it| *it.inner() > 0.0),
+            1.0
+        );
+        assert_eq!(
+            minimal(runner, any::<Negative<f64>>(), |it| *it.inner() < 0.0),
+            -1.0
+        );
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "quickcheck")]
+
+use numwit::{Negative, Positive};
+use quickcheck::{Arbitrary, Gen};
+
+quickcheck::quickcheck! {
+    fn positive_u8(n: Positive<u8>) -> bool {
+        n.into_inner() > 0
+    }
+    fn positive_i64(n: Positive<i64>) -> bool {
+        n.into_inner() > 0
+    }
+    fn negative_i64(n: Negative<i64>) -> bool {
+        n.into_inner() < 0
+    }
+    fn positive_f64(n: Positive<f64>) -> bool {
+        n.into_inner() > 0.0
+    }
+    fn negative_f64(n: Negative<f64>) -> bool {
+        n.into_inner() < 0.0
+    }
+}
+
+/// Repeatedly take the first shrink candidate, checking that every candidate is valid.
+fn minimal<T: Arbitrary>(mut value: T, valid: impl Fn(&T) -> bool) -> T {
+    loop {
+        let candidates = value.shrink().collect::<Vec<_>>();
+        assert!(candidates.iter().all(&valid));
+        match candidates.into_iter().next() {
+            Some(next) => value = next,
+            None => return value,
+        }
+    }
+}
+
+#[test]
+fn shrink_towards_one() {
+    let g = &mut Gen::new(1000);
+    for _ in 0..64 {
+        let valid = |it: &Positive<u32>| *it.inner() > 0;
+        assert_eq!(minimal(Positive::<u32>::arbitrary(g), valid), 1);
+        let valid = |it: &Positive<i32>| *it.inner() > 0;
+        assert_eq!(minimal(Positive::<i32>::arbitrary(g), valid), 1);
+        let valid = |it: &Negative<i32>| *it.inner() < 0;
+        assert_eq!(minimal(Negative::<i32>::arbitrary(g), valid), -1);
+        let valid = |it: &Positive<f64>| *it.inner() > 0.0;
+        assert_eq!(minimal(Positive::<f64>::arbitrary(g), valid), 1.0);
+        let valid = |it: &Negative<f64>| *it.inner() < 0.0;
+        assert_eq!(minimal(Negative::<f64>::arbitrary(g), valid), -1.0);
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "rand")]
+
+use num::One as _;
+use numwit::{Negative, Positive};
+use rand::{rngs::StdRng, Rng as _, SeedableRng as _};
+
+#[test]
+fn standard() {
+    let rng = &mut StdRng::seed_from_u64(0);
+    for _ in 0..10_000 {
+        assert!(rng.gen::<Positive<u8>>().into_inner() > 0);
+        assert!(rng.gen::<Positive<i64>>().into_inner() > 0);
+        assert!(rng.gen::<Negative<i8>>().into_inner() < 0);
+        let n = rng.gen::<Positive<f64>>().into_inner();
+        assert!(0.0 < n && n <= 1.0);
+        let n = rng.gen::<Negative<f32>>().into_inner();
+        assert!((-1.0..0.0).contains(&n));
+    }
+}
+
+#[test]
+fn gen_range() {
+    let rng = &mut StdRng::seed_from_u64(0);
+    let hi = Positive::new(10_u32).unwrap();
+    let mut seen = [false; 10];
+    for _ in 0..10_000 {
+        let n: Positive<u32> = rng.gen_range(Positive::one()..=hi);
+        assert!((1..=10).contains(n.inner()));
+        seen[n.into_inner() as usize - 1] = true;
+        let n: Positive<u32> = rng.gen_range(Positive::one()..hi);
+        assert!((1..10).contains(n.inner()));
+    }
+    assert!(seen.iter().all(|it| *it));
+
+    let (lo, hi) = (Negative::new(-10_i16).unwrap(), Negative::<i16>::one());
+    for _ in 0..10_000 {
+        let n = rng.gen_range(lo..=hi);
+        assert!((-10..=-1).contains(n.inner()));
+    }
+
+    let (lo, hi) = (Positive::new(0.5).unwrap(), Positive::new(2.0).unwrap());
+    for _ in 0..10_000 {
+        let n = rng.gen_range(lo..hi);
+        assert!((0.5..2.0).contains(n.inner()));
+    }
+}
+
+#[test]
+fn uniform() {
+    use rand::distributions::{Distribution as _, Uniform};
+
+    let rng = &mut StdRng::seed_from_u64(0);
+    let uniform =
+        Uniform::new_inclusive(Negative::new(-1.0).unwrap(), Negative::new(-0.5).unwrap());
+    for _ in 0..10_000 {
+        let n = uniform.sample(rng);
+        assert!((-1.0..=-0.5).contains(n.inner()));
+    }
+}
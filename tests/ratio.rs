@@ -0,0 +1,79 @@
+use num::{rational::Ratio, BigInt, BigRational};
+use numwit::{Negative, Positive};
+
+fn pos(n: i32) -> Positive<i32> {
+    Positive::new(n).unwrap()
+}
+
+fn neg(n: i32) -> Negative<i32> {
+    Negative::new(n).unwrap()
+}
+
+#[test]
+fn exact_division() {
+    let one = Positive::<Ratio<u32>>::from(Positive::new(1).unwrap());
+    let two = Positive::<Ratio<u32>>::from(Positive::new(2).unwrap());
+    let half: Positive<Ratio<u32>> = one / two;
+    assert_eq!(half, Ratio::new(1, 2));
+    let quarter: Positive<Ratio<u32>> = half / two;
+    assert_eq!(quarter, Ratio::new(1, 4));
+}
+
+#[test]
+fn from_parts() {
+    let r = Positive::from_parts(pos(4), pos(6));
+    assert_eq!(r, Ratio::new(2, 3));
+    assert_eq!(r.numer(), 2);
+    assert_eq!(r.denom(), 3);
+
+    let r = Negative::from_parts(neg(-4), pos(6));
+    assert_eq!(r, Ratio::new(-2, 3));
+    assert_eq!(r.numer(), -2);
+    assert_eq!(r.denom(), 3);
+}
+
+#[test]
+fn raw_parts() {
+    let r = Positive::new(Ratio::new_raw(-2, -3)).unwrap();
+    assert_eq!(r.numer(), 2);
+    assert_eq!(r.denom(), 3);
+
+    let r = Negative::new(Ratio::new_raw(2, -3)).unwrap();
+    assert_eq!(r.numer(), -2);
+    assert_eq!(r.denom(), 3);
+
+    let r = Positive::new(Ratio::new_raw(i32::MIN, -2)).unwrap();
+    assert_eq!(r.numer(), 1 << 30);
+    assert_eq!(r.denom(), 1);
+    let r = Negative::new(Ratio::new_raw(3, i32::MIN + 1)).unwrap();
+    assert_eq!(r.numer(), -3);
+    assert_eq!(r.denom(), i32::MAX);
+}
+
+#[test]
+#[should_panic = "the parts of the ratio overflow with a positive denominator"]
+fn raw_parts_overflow() {
+    // `num` can't compare this to zero either, so it can't be checked.
+    Positive::new_unchecked(Ratio::new_raw(i32::MIN, -1)).numer();
+}
+
+#[test]
+fn recip() {
+    assert_eq!(
+        Positive::from_parts(pos(2), pos(3)).recip(),
+        Ratio::new(3, 2)
+    );
+    let r = Negative::from_parts(neg(-2), pos(3)).recip();
+    assert_eq!(r, Ratio::new(-3, 2));
+    assert_eq!(r.denom(), 2);
+}
+
+#[test]
+fn big() {
+    let numer = Positive::new(BigInt::from(1) << 100).unwrap();
+    let denom = Positive::new(BigInt::from(3)).unwrap();
+    let r: Positive<BigRational> = Positive::from_parts(numer.clone(), denom);
+    assert_eq!(r.recip().denom(), numer);
+    let neg: Negative<BigRational> = -&r;
+    assert_eq!(neg.numer(), -numer.into_inner());
+}
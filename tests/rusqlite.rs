@@ -0,0 +1,43 @@
+#![cfg(feature = "rusqlite")]
+
+use numwit::{Negative, NotNegative, NotPositive, Positive};
+use rusqlite::Connection;
+
+#[test]
+fn round_trip() {
+    let conn = Connection::open_in_memory().unwrap();
+    let (price, delta) = (
+        Positive::new(2_i64).unwrap(),
+        Negative::new(-3_i64).unwrap(),
+    );
+    let row = conn
+        .query_row("SELECT ?1, ?2", (price, delta), |row| {
+            Ok((
+                row.get::<_, Positive<i64>>(0)?,
+                row.get::<_, Negative<i64>>(1)?,
+            ))
+        })
+        .unwrap();
+    assert_eq!(row, (price, delta));
+}
+
+#[test]
+fn conversion_error() {
+    let conn = Connection::open_in_memory().unwrap();
+
+    let rusqlite::Error::FromSqlConversionFailure(_, _, e) = conn
+        .query_row("SELECT 0", (), |row| row.get::<_, Positive<i64>>(0))
+        .unwrap_err()
+    else {
+        panic!("expected a conversion error")
+    };
+    assert_eq!(e.downcast_ref::<NotPositive<i64>>().unwrap().0, 0);
+
+    let rusqlite::Error::FromSqlConversionFailure(_, _, e) = conn
+        .query_row("SELECT 1", (), |row| row.get::<_, Negative<i64>>(0))
+        .unwrap_err()
+    else {
+        panic!("expected a conversion error")
+    };
+    assert_eq!(e.downcast_ref::<NotNegative<i64>>().unwrap().0, 1);
+}
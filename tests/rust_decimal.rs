@@ -0,0 +1,75 @@
+#![cfg(feature = "rust_decimal")]
+
+use num::{CheckedAdd as _, CheckedDiv as _, CheckedMul as _};
+use numwit::{Negative, Positive};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+fn pos(s: &str) -> Positive<Decimal> {
+    Positive::new(dec(s)).unwrap()
+}
+
+fn neg(s: &str) -> Negative<Decimal> {
+    Negative::new(dec(s)).unwrap()
+}
+
+#[test]
+fn ops() {
+    assert_eq!(pos("1.5") + pos("2.25"), dec("3.75"));
+    assert_eq!(neg("-1.5") + neg("-2.25"), dec("-3.75"));
+    assert_eq!(pos("1.5") - neg("-2.25"), dec("3.75"));
+    assert_eq!(neg("-1.5") - pos("2.25"), dec("-3.75"));
+    assert_eq!(pos("1.5") * pos("2"), dec("3"));
+    let product: Positive<Decimal> = neg("-1.5") * neg("-2");
+    assert_eq!(product, dec("3"));
+    assert_eq!(pos("3") / neg("-2"), dec("-1.5"));
+    assert_eq!(pos("1.5") - pos("2.25"), dec("-0.75"));
+    assert_eq!(-pos("1.5"), dec("-1.5"));
+
+    let mut n = pos("1.5");
+    n += &pos("0.5");
+    n /= pos("4");
+    assert_eq!(n, dec("0.5"));
+}
+
+#[test]
+fn checked() {
+    assert_eq!(pos("1.5").checked_add(&pos("2.25")), Some(pos("3.75")));
+    assert_eq!(pos("1.5").checked_sub(&neg("-2.25")), Some(pos("3.75")));
+    assert_eq!(neg("-1.5").checked_sub(&pos("2.25")), Some(neg("-3.75")));
+    assert_eq!(pos("1.5").checked_div(&pos("2")), Some(pos("0.75")));
+
+    let max = Positive::new(Decimal::MAX).unwrap();
+    assert_eq!(max.checked_add(&pos("1")), None);
+    assert_eq!(max.checked_mul(&pos("2")), None);
+    let min = Negative::new(Decimal::MIN).unwrap();
+    assert_eq!(min.checked_add(&neg("-1")), None);
+
+    // Rounds to zero.
+    let tiny = pos("0.0000000000000000000000000001");
+    assert_eq!(tiny.checked_mul(&tiny), None);
+    assert_eq!(tiny.checked_div(&max), None);
+}
+
+#[test]
+fn round_dp() {
+    assert_eq!(pos("1.25").round_dp(1).unwrap(), dec("1.2"));
+    assert_eq!(
+        pos("1.25")
+            .round_dp_with_strategy(1, RoundingStrategy::AwayFromZero)
+            .unwrap(),
+        dec("1.3")
+    );
+    assert_eq!(
+        neg("-0.005").round_dp(2).unwrap_err().into_inner(),
+        dec("0")
+    );
+    assert_eq!(pos("0.004").round_dp(2).unwrap_err().into_inner(), dec("0"));
+    assert_eq!(
+        pos("0.004").round_dp(2).unwrap_err().to_string(),
+        "The value 0.00 was not positive"
+    );
+}
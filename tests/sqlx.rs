@@ -0,0 +1,59 @@
+#![cfg(feature = "sqlx")]
+
+use numwit::{Negative, NotNegative, NotPositive, Positive};
+use sqlx::{Connection as _, SqliteConnection};
+
+async fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE t (price INTEGER NOT NULL, delta INTEGER NOT NULL)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    conn
+}
+
+#[tokio::test]
+async fn round_trip() {
+    let mut conn = connect().await;
+    sqlx::query("INSERT INTO t VALUES (?, ?)")
+        .bind(Positive::new(2_i64).unwrap())
+        .bind(Negative::new(-3_i64).unwrap())
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    let (price, delta) = sqlx::query_as::<_, (Positive<i64>, Negative<i64>)>("SELECT * FROM t")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(price, 2);
+    assert_eq!(delta, -3);
+}
+
+#[tokio::test]
+async fn decode_error() {
+    let mut conn = connect().await;
+    sqlx::query("INSERT INTO t VALUES (0, 1)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let sqlx::Error::ColumnDecode { source, .. } =
+        sqlx::query_scalar::<_, Positive<i64>>("SELECT price FROM t")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap_err()
+    else {
+        panic!("expected a decode error")
+    };
+    assert_eq!(source.downcast_ref::<NotPositive<i64>>().unwrap().0, 0);
+
+    let sqlx::Error::ColumnDecode { source, .. } =
+        sqlx::query_scalar::<_, Negative<i64>>("SELECT delta FROM t")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap_err()
+    else {
+        panic!("expected a decode error")
+    };
+    assert_eq!(source.downcast_ref::<NotNegative<i64>>().unwrap().0, 1);
+}
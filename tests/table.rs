@@ -0,0 +1,86 @@
+use num::{BigInt, BigUint, One as _};
+use numwit::{Even, Negative, Odd, Positive};
+
+fn pos(n: i64) -> Positive<BigInt> {
+    Positive::new(BigInt::from(n)).unwrap()
+}
+
+fn neg(n: i64) -> Negative<BigInt> {
+    Negative::new(BigInt::from(n)).unwrap()
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn witness_rhs() {
+    let (a, b) = (pos(2), pos(3));
+    let sum: Positive<BigInt> = &a + &b;
+    assert_eq!(sum, BigInt::from(5));
+    let sum: Positive<BigInt> = &a + b.clone();
+    assert_eq!(sum, BigInt::from(5));
+    let sum: Positive<BigInt> = a.clone() + &b;
+    assert_eq!(sum, BigInt::from(5));
+    let sum: Positive<BigInt> = a.clone() + b.clone();
+    assert_eq!(sum, BigInt::from(5));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn unknown_output() {
+    let (a, b) = (pos(2), neg(-3));
+    assert_eq!(&a + &b, BigInt::from(-1));
+    assert_eq!(&a + b.clone(), BigInt::from(-1));
+    assert_eq!(a.clone() + &b, BigInt::from(-1));
+    assert_eq!(&a - &a, BigInt::from(0));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn unsigned_rhs() {
+    let a = Positive::<BigUint>::one();
+    let two = BigUint::from(2u8);
+    let sum: Positive<BigUint> = &a + &two;
+    assert_eq!(sum, BigUint::from(3u8));
+    let sum: Positive<BigUint> = &a + two.clone();
+    assert_eq!(sum, BigUint::from(3u8));
+    let sum: Positive<BigUint> = a + &two;
+    assert_eq!(sum, BigUint::from(3u8));
+
+    let a = Positive::new(6u8).unwrap();
+    assert_eq!(&a / &2u8, 3);
+    assert_eq!(&a / 2u8, 3);
+    assert_eq!(a / &2u8, 3);
+}
+
+#[test]
+fn assign() {
+    let mut a = pos(2);
+    a += &pos(3);
+    a *= &pos(2);
+    a -= &neg(-1);
+    assert_eq!(a, BigInt::from(11));
+
+    let mut b = neg(-6);
+    b /= &pos(3);
+    assert_eq!(b, BigInt::from(-2));
+
+    let mut c = Positive::<BigUint>::one();
+    c += &BigUint::from(2u8);
+    c /= &BigUint::from(3u8);
+    assert_eq!(c, BigUint::from(1u8));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn parity() {
+    let even = Even::new(BigInt::from(2)).unwrap();
+    let odd = Odd::new(BigInt::from(3)).unwrap();
+    let sum: Odd<BigInt> = &even + &odd;
+    assert_eq!(sum, BigInt::from(5));
+    let product: Even<BigInt> = &even * odd.clone();
+    assert_eq!(product, BigInt::from(6));
+    let mut n = odd.clone();
+    n += &even;
+    assert_eq!(n, BigInt::from(5));
+    let negated: Odd<BigInt> = -&odd;
+    assert_eq!(negated, BigInt::from(-3));
+}
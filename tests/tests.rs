@@ -1,5 +1,7 @@
+use std::fmt;
+
 use num::One as _;
-use numwit::{Negative, Positive};
+use numwit::{Describe, Negative, Positive, Predicate, Witness};
 
 type PosU8 = Positive<u8>;
 type PosI8 = Positive<i8>;
@@ -62,9 +64,8 @@ fn add_assign_pos_unsigned() {
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Add       | `Negative` | `impl Unsigned` | ?          | No          |
 #[test]
-#[ignore = "Negative<impl Unsigned> cannot be constructed"]
 fn add_neg_unsigned() {
-    // assert_eq!(NegI8::one() + 1, 0);
+    assert_eq!(NegI8::one() + 1, 0);
 }
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
@@ -125,17 +126,15 @@ fn sub_pos_unsigned() {
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Sub       | `Negative` | `impl Unsigned` | `Negative` | Yes         |
 #[test]
-#[ignore = "Negative<impl Unsigned> cannot be constructed"]
 fn sub_neg_unsigned() {
-    // assert_eq!(NegI8::one() - 1, -2);
+    assert_eq!(NegI8::one() - 1, -2);
 }
 
 #[test]
-#[ignore = "Negative<impl Unsigned> cannot be constructed"]
 fn sub_assign_neg_unsigned() {
-    // let mut n = NegI8::one();
-    // n -= 1;
-    // assert_eq!(n, -2);
+    let mut n = NegI8::one();
+    n -= 1;
+    assert_eq!(n, -2);
 }
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
@@ -196,9 +195,15 @@ fn mul_pos_unsigned() {
 // | --------- | ---------- | --------------- | ---------- | ----------- |
 // | Mul       | `Negative` | `impl Unsigned` | ?          | No          |
 #[test]
-#[ignore = "Negative<impl Unsigned> cannot be constructed"]
 fn mul_neg_unsigned() {
-    // assert_eq!(NegI8::one() * 0, 0);
+    assert_eq!(NegI8::one() * 0, 0);
+    assert_eq!(NegI8::one() * 128, i8::MIN);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic = "attempt to multiply with overflow")]
+fn mul_neg_unsigned_overflow() {
+    assert_eq!(NegI8::one() * 255, 1);
 }
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
@@ -264,11 +269,138 @@ fn div_assign_pos_unsigned() {
 
 // | Operation | LHS        | RHS             | Output     | Assignable? |
 // | --------- | ---------- | --------------- | ---------- | ----------- |
-// | Div       | `Negative` | `impl Unsigned` | `Negative` | Yes         |
+// | Div       | `Negative` | `impl Unsigned` | ?          | No          |
 #[test]
-#[ignore = "Negative<impl Unsigned> cannot be constructed"]
-fn div_neg_unsigned() {}
+fn div_neg_unsigned() {
+    assert_eq!(NegI8::one() / 1, -1);
+    assert_eq!(NegI8::new(i8::MIN).unwrap() / 1, i8::MIN);
+    assert_eq!(NegI8::one() / 2, 0);
+}
 
 #[test]
-#[ignore = "Negative<impl Unsigned> cannot be constructed"]
-fn div_assign_neg_unsigned() {}
+fn slice_from() {
+    assert_eq!(
+        Positive::slice_from(&[1_u8, 2, 3]).unwrap(),
+        [PosU8::one(), PosU8::new(2).unwrap(), PosU8::new(3).unwrap()]
+    );
+    assert_eq!(Positive::slice_from(&[1_i8, 0, -1]).unwrap_err().0, 1);
+    assert_eq!(
+        Negative::slice_from(&[-1_i8, -2]).unwrap(),
+        [NegI8::one(), NegI8::new(-2).unwrap()]
+    );
+    assert_eq!(*Negative::slice_from(&[-1_i8, 1]).unwrap_err().1 .0, 1);
+}
+
+#[test]
+fn slice_from_many() {
+    let mut v = vec![1.0_f64; 200];
+    assert!(Positive::slice_from(&v).is_ok());
+    v[130] = f64::NAN;
+    assert_eq!(Positive::slice_from(&v).unwrap_err().0, 130);
+    v[70] = 0.0;
+    assert_eq!(Positive::slice_from(&v).unwrap_err().0, 70);
+}
+
+#[test]
+fn as_inner_slice() {
+    let witnesses = [PosU8::one(), PosU8::new(2).unwrap()];
+    assert_eq!(Positive::as_inner_slice(&witnesses), [1, 2]);
+    let witnesses = [NegI8::one(), NegI8::new(-2).unwrap()];
+    assert_eq!(Negative::as_inner_slice(&witnesses), [-1, -2]);
+}
+
+#[test]
+fn try_from_vec() {
+    let v = vec![1_u8, 2, 3];
+    let ptr = v.as_ptr();
+    let witnesses = Positive::try_from_vec(v).unwrap();
+    assert_eq!(witnesses, [1, 2, 3]);
+    let v = Positive::into_inner_vec(witnesses);
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v.as_ptr(), ptr);
+
+    let (ix, v) = Negative::try_from_vec(vec![-1_i8, 0, -3]).unwrap_err();
+    assert_eq!((ix, v), (1, vec![-1, 0, -3]));
+    let witnesses = Negative::try_from_vec(vec![-1_i8, -2]).unwrap();
+    assert_eq!(Negative::into_inner_vec(witnesses), [-1, -2]);
+}
+
+struct IsEven;
+
+impl Describe for IsEven {
+    fn name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Even")
+    }
+    fn description(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("even")
+    }
+}
+
+impl Predicate<u32> for IsEven {
+    fn test(value: &u32) -> bool {
+        value.is_multiple_of(2)
+    }
+}
+
+type Even = Witness<u32, IsEven>;
+
+#[test]
+fn custom_predicate() {
+    let even = Even::new(4).unwrap();
+    assert_eq!(even, 4);
+    assert_eq!(format!("{even:?}"), "Even(4)");
+    assert_eq!(even.map(|it| it + 2).unwrap(), 6);
+    assert_eq!(even.map(|it| it + 1).unwrap_err().0, 5);
+    assert_eq!(
+        Even::new(3).unwrap_err().to_string(),
+        "The value 3 was not even"
+    );
+    assert_eq!(Even::slice_from(&[2, 4, 5]).unwrap_err().0, 2);
+
+    assert_eq!(serde_json::from_str::<Even>("8").unwrap(), 8);
+    assert!(serde_json::from_str::<Even>("7").is_err());
+    assert_eq!(serde_json::to_string(&even).unwrap(), "4");
+}
+
+#[test]
+fn formatting() {
+    assert_eq!(format!("{:?}", PosU8::one()), "Positive(1)");
+    assert_eq!(format!("{:?}", NegI8::one()), "Negative(-1)");
+    assert_eq!(
+        PosI8::new(0).unwrap_err().to_string(),
+        "The value 0 was not positive"
+    );
+    assert_eq!(
+        NegI8::new(0).unwrap_err().to_string(),
+        "The value 0 was not negative"
+    );
+}
+
+#[test]
+fn assert() {
+    PosU8::one().assert();
+    NegI8::one().debug_assert();
+}
+
+#[test]
+#[should_panic = "value was not positive"]
+fn assert_panics() {
+    PosI8::new_unchecked(-1).assert();
+}
+
+#[test]
+fn checked() {
+    use num::{CheckedAdd as _, CheckedDiv as _, CheckedMul as _};
+
+    let max = PosU8::new(u8::MAX).unwrap();
+    assert_eq!(max.checked_add(&PosU8::one()), None);
+    assert_eq!(max.checked_mul(&PosU8::one()), Some(max));
+    assert_eq!(PosI8::one().checked_sub(&NegI8::one()), PosI8::new(2).ok());
+    assert_eq!(NegI8::one().checked_add(&NegI8::one()), NegI8::new(-2).ok());
+    assert_eq!(
+        NegI8::new(i8::MIN).unwrap().checked_sub(&PosI8::one()),
+        None
+    );
+    // Truncates to zero.
+    assert_eq!(PosU8::one().checked_div(&max), None);
+}
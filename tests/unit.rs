@@ -0,0 +1,94 @@
+use numwit::{OpenUnit, Positive, PositiveProbability, Probability};
+
+fn p(f: f64) -> Probability<f64> {
+    Probability::new(f).unwrap()
+}
+
+fn pp(f: f64) -> PositiveProbability<f64> {
+    PositiveProbability::new(f).unwrap()
+}
+
+fn u(f: f64) -> OpenUnit<f64> {
+    OpenUnit::new(f).unwrap()
+}
+
+#[test]
+fn new() {
+    assert!(Probability::new(0.0).is_ok());
+    assert!(Probability::new(1.0).is_ok());
+    assert!(Probability::new(f64::NAN).is_err());
+    assert_eq!(
+        Probability::new(1.5).unwrap_err().to_string(),
+        "The value 1.5 was not in [0, 1]"
+    );
+    assert!(OpenUnit::new(0.0_f32).is_err());
+    assert!(OpenUnit::new(1.0_f32).is_err());
+    assert!(OpenUnit::new(f64::from_bits(1)).is_ok());
+    assert_eq!(
+        OpenUnit::new(1.0).unwrap_err().to_string(),
+        "The value 1 was not in (0, 1)"
+    );
+    assert!(PositiveProbability::new(0.0).is_err());
+    assert!(PositiveProbability::new(1.0).is_ok());
+    assert_eq!(
+        PositiveProbability::new(-0.0).unwrap_err().to_string(),
+        "The value -0 was not in (0, 1]"
+    );
+}
+
+#[test]
+fn complement() {
+    assert_eq!(p(0.25).complement(), 0.75);
+    assert_eq!(p(0.0).complement(), 1.0);
+    assert_eq!(u(0.25).complement(), 0.75);
+    assert_eq!(u(1e-20).complement(), 1.0);
+    assert_eq!(pp(1.0).complement(), 0.0);
+}
+
+#[test]
+fn mul() {
+    assert_eq!(p(0.5) * p(0.5), 0.25);
+    assert_eq!(p(0.0) * p(1.0), 0.0);
+    let mut n = p(0.5);
+    n *= p(0.5);
+    assert_eq!(n, 0.25);
+
+    let product: Probability<f64> = u(0.5) * u(0.5);
+    assert_eq!(product, 0.25);
+    let tiny = u(f64::from_bits(1));
+    assert_eq!(tiny * tiny, 0.0);
+    assert_eq!(u(0.5).checked_mul(u(0.5)).unwrap(), 0.25);
+    assert_eq!(tiny.checked_mul(tiny), None);
+    assert_eq!(u(1e-200).checked_mul(u(1e-200)), None);
+
+    let product: Probability<f64> = pp(1.0) * pp(0.5);
+    assert_eq!(product, 0.5);
+    assert_eq!(pp(1.0).checked_mul(pp(1.0)).unwrap(), 1.0);
+    assert_eq!(pp(1e-200).checked_mul(pp(1e-200)), None);
+}
+
+#[test]
+fn ln() {
+    assert_eq!(u(0.5).ln(), 0.5_f64.ln());
+    assert!(u(f64::from_bits(1)).ln().into_inner().is_finite());
+    assert!(u(1.0 - f64::EPSILON / 2.0).ln().into_inner() < 0.0);
+    assert_eq!(pp(1.0).ln(), 0.0);
+    assert_eq!(pp(0.5).ln(), 0.5_f64.ln());
+}
+
+#[test]
+fn conversions() {
+    let pos: Positive<f64> = u(0.5).into();
+    assert_eq!(pos, 0.5);
+    let prob: Probability<f64> = u(0.5).into();
+    assert_eq!(prob, 0.5);
+    assert_eq!(*u(0.5).as_positive(), pos);
+    assert_eq!(Positive::try_from(p(0.5)).unwrap(), 0.5);
+    assert!(Positive::try_from(p(0.0)).is_err());
+
+    let half: PositiveProbability<f64> = u(0.5).into();
+    assert_eq!(half, 0.5);
+    assert_eq!(Positive::from(pp(1.0)), 1.0);
+    assert_eq!(Probability::from(pp(1.0)), 1.0);
+    assert_eq!(*pp(1.0).as_positive(), 1.0);
+}
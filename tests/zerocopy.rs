@@ -0,0 +1,25 @@
+#![cfg(feature = "zerocopy")]
+
+use numwit::{Negative, Positive};
+use zerocopy::{FromBytes as _, IntoBytes as _};
+
+#[test]
+fn round_trip() {
+    let buf = [-1_i32, -2, -3];
+    let bytes = Negative::slice_from(&buf).unwrap().as_bytes();
+    assert_eq!(bytes, buf.as_bytes());
+
+    let ints = <[i32]>::ref_from_bytes(bytes).unwrap();
+    assert_eq!(
+        Negative::slice_from(ints).unwrap(),
+        Negative::slice_from(&buf).unwrap()
+    );
+}
+
+#[test]
+fn first_bad_index() {
+    let buf = [1_u64, 2, 0, 4];
+    let ints = <[u64]>::ref_from_bytes(buf.as_bytes()).unwrap();
+    let (ix, err) = Positive::slice_from(ints).unwrap_err();
+    assert_eq!((ix, *err.0), (2, 0));
+}